@@ -1,4 +1,34 @@
 // Builder Pattern Demo
+use std::env;
+use std::fs;
+use std::marker::PhantomData;
+
+use super::conversion::{Conversion, TypedValue};
+
+/// Typestate markers tracking whether a required builder field has been set.
+pub struct Set;
+pub struct Unset;
+
+/// Errors raised while feeding the builder from an untyped string via
+/// `set_str`. The [`Conversion`]/[`TypedValue`] coercion itself is shared
+/// with the Template Method demo (see [`super::conversion`]); this error
+/// type stays local since it's specific to builder fields.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    UnknownField(String),
+    ParseFailed { field: String, raw: String },
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::UnknownField(field) => write!(f, "unknown builder field '{field}'"),
+            ConversionError::ParseFailed { field, raw } => {
+                write!(f, "could not parse '{raw}' for field '{field}'")
+            }
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
@@ -10,16 +40,59 @@ pub struct DatabaseConfig {
     pub connection_timeout: u64,
 }
 
-pub struct DatabaseConfigBuilder {
+/// Which tier last set a field. Ordered so a lower tier can never clobber a
+/// higher one, no matter what order the builder's calls happen in - e.g. an
+/// explicit `.host(...)` call always wins over `merge_env`/`from_toml`, even
+/// if one of those runs afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+enum Provenance {
+    #[default]
+    Unset,
+    File,
+    Env,
+    Explicit,
+}
+
+/// Sets `*slot` to `value` only if `source` is at least as high-precedence
+/// as whatever last set it.
+fn set_field<T>(slot: &mut Option<T>, provenance: &mut Provenance, value: T, source: Provenance) {
+    if source >= *provenance {
+        *slot = Some(value);
+        *provenance = source;
+    }
+}
+
+/// One [`Provenance`] per builder field, tracked alongside the values
+/// themselves.
+#[derive(Debug, Clone, Copy, Default)]
+struct FieldProvenance {
+    host: Provenance,
+    port: Provenance,
+    username: Provenance,
+    password: Provenance,
+    ssl: Provenance,
+    connection_timeout: Provenance,
+}
+
+/// A builder parameterized over one typestate marker per required field
+/// (`HostState`, `PortState`, `UserState`, `PassState`). Once all four are
+/// `Set`, `build()` becomes available and is infallible - missing a
+/// required field is a compile error rather than a runtime `Result`.
+/// Every field, required or optional, also tracks a [`Provenance`] so
+/// `merge_env`/`from_toml` can't override an explicit setter regardless of
+/// call order.
+pub struct DatabaseConfigBuilder<HostState = Unset, PortState = Unset, UserState = Unset, PassState = Unset> {
     host: Option<String>,
     port: Option<u16>,
     username: Option<String>,
     password: Option<String>,
     ssl: bool,
     connection_timeout: Option<u64>,
+    provenance: FieldProvenance,
+    _marker: PhantomData<(HostState, PortState, UserState, PassState)>,
 }
 
-impl DatabaseConfigBuilder {
+impl DatabaseConfigBuilder<Unset, Unset, Unset, Unset> {
     pub fn new() -> Self {
         DatabaseConfigBuilder {
             host: None,
@@ -28,46 +101,215 @@ impl DatabaseConfigBuilder {
             password: None,
             ssl: false,
             connection_timeout: None,
+            provenance: FieldProvenance::default(),
+            _marker: PhantomData,
         }
     }
-    
-    pub fn host(mut self, host: &str) -> Self {
-        self.host = Some(host.to_string());
-        self
+
+    /// Seeds a builder from a `[database]` table in a TOML file. This demo
+    /// keeps a minimal line-based parser instead of pulling in a TOML
+    /// crate - good enough for flat `key = value` pairs. Because the
+    /// fields come from a dynamic source, the typestate markers can't be
+    /// statically upgraded; use `build_checked()` on the result. Fields set
+    /// here carry [`Provenance::File`], the lowest tier.
+    pub fn from_toml(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {path}: {e}"))?;
+
+        let mut builder = DatabaseConfigBuilder::new();
+        let mut in_database_table = false;
+
+        for raw_line in contents.lines() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                in_database_table = line.trim_matches(['[', ']']) == "database";
+                continue;
+            }
+            if !in_database_table {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = value.trim().trim_matches('"');
+
+            match key {
+                "host" => set_field(&mut builder.host, &mut builder.provenance.host, value.to_string(), Provenance::File),
+                "port" => {
+                    if let Ok(port) = value.parse() {
+                        set_field(&mut builder.port, &mut builder.provenance.port, port, Provenance::File);
+                    }
+                }
+                "username" => set_field(&mut builder.username, &mut builder.provenance.username, value.to_string(), Provenance::File),
+                "password" => set_field(&mut builder.password, &mut builder.provenance.password, value.to_string(), Provenance::File),
+                "ssl" => {
+                    if Provenance::File >= builder.provenance.ssl {
+                        builder.ssl = value.parse().unwrap_or(false);
+                        builder.provenance.ssl = Provenance::File;
+                    }
+                }
+                "connection_timeout" => {
+                    if let Ok(timeout) = value.parse() {
+                        set_field(&mut builder.connection_timeout, &mut builder.provenance.connection_timeout, timeout, Provenance::File);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(builder)
     }
-    
-    pub fn port(mut self, port: u16) -> Self {
-        self.port = Some(port);
-        self
+}
+
+impl<HostState, PortState, UserState, PassState> DatabaseConfigBuilder<HostState, PortState, UserState, PassState> {
+    /// Moves every field into a builder with different typestate markers.
+    /// Used internally by the required-field setters below.
+    fn retype<H2, P2, U2, W2>(self) -> DatabaseConfigBuilder<H2, P2, U2, W2> {
+        DatabaseConfigBuilder {
+            host: self.host,
+            port: self.port,
+            username: self.username,
+            password: self.password,
+            ssl: self.ssl,
+            connection_timeout: self.connection_timeout,
+            provenance: self.provenance,
+            _marker: PhantomData,
+        }
     }
-    
-    pub fn username(mut self, username: &str) -> Self {
-        self.username = Some(username.to_string());
-        self
+
+    pub fn host(mut self, host: &str) -> DatabaseConfigBuilder<Set, PortState, UserState, PassState> {
+        set_field(&mut self.host, &mut self.provenance.host, host.to_string(), Provenance::Explicit);
+        self.retype()
     }
-    
-    pub fn password(mut self, password: &str) -> Self {
-        self.password = Some(password.to_string());
-        self
+
+    pub fn port(mut self, port: u16) -> DatabaseConfigBuilder<HostState, Set, UserState, PassState> {
+        set_field(&mut self.port, &mut self.provenance.port, port, Provenance::Explicit);
+        self.retype()
+    }
+
+    pub fn username(mut self, username: &str) -> DatabaseConfigBuilder<HostState, PortState, Set, PassState> {
+        set_field(&mut self.username, &mut self.provenance.username, username.to_string(), Provenance::Explicit);
+        self.retype()
+    }
+
+    pub fn password(mut self, password: &str) -> DatabaseConfigBuilder<HostState, PortState, UserState, Set> {
+        set_field(&mut self.password, &mut self.provenance.password, password.to_string(), Provenance::Explicit);
+        self.retype()
     }
-    
+
     pub fn ssl(mut self, ssl: bool) -> Self {
         self.ssl = ssl;
+        self.provenance.ssl = Provenance::Explicit;
         self
     }
-    
+
     pub fn connection_timeout(mut self, timeout: u64) -> Self {
-        self.connection_timeout = Some(timeout);
+        set_field(&mut self.connection_timeout, &mut self.provenance.connection_timeout, timeout, Provenance::Explicit);
+        self
+    }
+
+    /// Merges prefixed environment variables into this builder, e.g. with
+    /// `prefix = "MYAPP"`: `MYAPP_HOST`, `MYAPP_PORT`, `MYAPP_SSL`,
+    /// `MYAPP_CONNECTION_TIMEOUT`. Unset/unparsable variables are left
+    /// untouched rather than erroring, so partial env config is fine. Each
+    /// field set here carries [`Provenance::Env`], so it overrides a value
+    /// from `from_toml` but can never override an explicit setter, no
+    /// matter the call order. Like the other dynamic sources, this never
+    /// upgrades the typestate markers - use `build_checked()` afterwards.
+    pub fn merge_env(mut self, prefix: &str) -> Self {
+        if let Ok(host) = env::var(format!("{prefix}_HOST")) {
+            set_field(&mut self.host, &mut self.provenance.host, host, Provenance::Env);
+        }
+        if let Ok(port) = env::var(format!("{prefix}_PORT")) {
+            if let Ok(port) = port.parse() {
+                set_field(&mut self.port, &mut self.provenance.port, port, Provenance::Env);
+            }
+        }
+        if let Ok(username) = env::var(format!("{prefix}_USERNAME")) {
+            set_field(&mut self.username, &mut self.provenance.username, username, Provenance::Env);
+        }
+        if let Ok(password) = env::var(format!("{prefix}_PASSWORD")) {
+            set_field(&mut self.password, &mut self.provenance.password, password, Provenance::Env);
+        }
+        if let Ok(ssl) = env::var(format!("{prefix}_SSL")) {
+            if let Ok(ssl) = ssl.parse() {
+                if Provenance::Env >= self.provenance.ssl {
+                    self.ssl = ssl;
+                    self.provenance.ssl = Provenance::Env;
+                }
+            }
+        }
+        if let Ok(timeout) = env::var(format!("{prefix}_CONNECTION_TIMEOUT")) {
+            if let Ok(timeout) = timeout.parse() {
+                set_field(&mut self.connection_timeout, &mut self.provenance.connection_timeout, timeout, Provenance::Env);
+            }
+        }
         self
     }
-    
-    pub fn build(self) -> Result<DatabaseConfig, String> {
+
+    /// Sets a field by name from an untyped string, parsing it through the
+    /// [`Conversion`] appropriate for that field. Gives CLI args / generic
+    /// string maps a generic, data-driven path into the builder alongside
+    /// the statically-typed setters above. Treated as an explicit call for
+    /// precedence purposes, just like `host()`/`port()`/etc. Like
+    /// `merge_env`/`from_toml`, this can't prove a required field is set at
+    /// compile time - pair it with `build_checked()`.
+    pub fn set_str(mut self, field: &str, raw: &str) -> Result<Self, ConversionError> {
+        let conversion = match field {
+            "host" | "username" | "password" => Conversion::Bytes,
+            "port" | "connection_timeout" => Conversion::Integer,
+            "ssl" => Conversion::Boolean,
+            _ => return Err(ConversionError::UnknownField(field.to_string())),
+        };
+
+        let value = conversion.parse(raw).ok_or_else(|| ConversionError::ParseFailed {
+            field: field.to_string(),
+            raw: raw.to_string(),
+        })?;
+
+        match (field, value) {
+            ("host", TypedValue::Bytes(v)) => set_field(&mut self.host, &mut self.provenance.host, v, Provenance::Explicit),
+            ("username", TypedValue::Bytes(v)) => set_field(&mut self.username, &mut self.provenance.username, v, Provenance::Explicit),
+            ("password", TypedValue::Bytes(v)) => set_field(&mut self.password, &mut self.provenance.password, v, Provenance::Explicit),
+            ("port", TypedValue::Integer(v)) => {
+                let port = v.try_into().map_err(|_| ConversionError::ParseFailed {
+                    field: field.to_string(),
+                    raw: raw.to_string(),
+                })?;
+                set_field(&mut self.port, &mut self.provenance.port, port, Provenance::Explicit);
+            }
+            ("connection_timeout", TypedValue::Integer(v)) => {
+                let timeout = v.try_into().map_err(|_| ConversionError::ParseFailed {
+                    field: field.to_string(),
+                    raw: raw.to_string(),
+                })?;
+                set_field(&mut self.connection_timeout, &mut self.provenance.connection_timeout, timeout, Provenance::Explicit);
+            }
+            ("ssl", TypedValue::Boolean(v)) => {
+                self.ssl = v;
+                self.provenance.ssl = Provenance::Explicit;
+            }
+            _ => unreachable!("conversion result type always matches the field's conversion"),
+        }
+
+        Ok(self)
+    }
+
+    /// Fallible build for builders fed by a dynamic source (env, TOML,
+    /// `set_str`) where the typestate markers can't prove every required
+    /// field is present.
+    pub fn build_checked(self) -> Result<DatabaseConfig, String> {
         let host = self.host.ok_or("Host is required")?;
         let port = self.port.ok_or("Port is required")?;
         let username = self.username.ok_or("Username is required")?;
         let password = self.password.ok_or("Password is required")?;
         let connection_timeout = self.connection_timeout.unwrap_or(30);
-        
+
         Ok(DatabaseConfig {
             host,
             port,
@@ -79,12 +321,28 @@ impl DatabaseConfigBuilder {
     }
 }
 
+impl DatabaseConfigBuilder<Set, Set, Set, Set> {
+    /// Infallible build, only available once every required field's
+    /// typestate marker is `Set` - a zero-cost, compile-time guarantee
+    /// that `build_checked()`'s runtime checks can't express.
+    pub fn build(self) -> DatabaseConfig {
+        DatabaseConfig {
+            host: self.host.unwrap(),
+            port: self.port.unwrap(),
+            username: self.username.unwrap(),
+            password: self.password.unwrap(),
+            ssl: self.ssl,
+            connection_timeout: self.connection_timeout.unwrap_or(30),
+        }
+    }
+}
+
 pub fn demo_builder() {
     println!("🏗️  BUILDER PATTERN DEMO");
     println!("{}", "=".repeat(60));
     println!("\nThis pattern constructs complex objects step by step.");
     println!("Rust Benefit: Compile-time safety with method chaining.");
-    
+
     println!("\n📝 Example 1: Building a valid database configuration");
     let config = DatabaseConfigBuilder::new()
         .host("localhost")
@@ -93,22 +351,22 @@ pub fn demo_builder() {
         .password("secret")
         .ssl(true)
         .connection_timeout(60)
-        .build()
-        .expect("Failed to build database config");
-    
+        .build(); // infallible: the typestate guarantees every required field is set
+
     println!("✅ Configuration built: {:?}", config);
-    
-    println!("\n📝 Example 2: Building with missing required fields");
+
+    println!("\n📝 Example 2: Missing required fields is now a compile error");
+    println!("   (e.g. `.host(\"localhost\").port(5432).build()` - no username/password - won't compile)");
     let result = DatabaseConfigBuilder::new()
         .host("localhost")
         .port(5432)
-        .build();
-    
+        .build_checked();
+
     match result {
         Ok(_) => println!("✅ Config built successfully"),
         Err(e) => println!("❌ Error: {}", e),
     }
-    
+
     println!("\n📝 Example 3: Builder with default values");
     let config = DatabaseConfigBuilder::new()
         .host("production.example.com")
@@ -116,14 +374,59 @@ pub fn demo_builder() {
         .username("dbuser")
         .password("secure123")
         .ssl(false)
-        .build()
-        .unwrap();
-    
+        .build();
+
     println!("✅ Configuration with defaults: {:?}", config);
-    
+
+    println!("\n📝 Example 4: Layered config (file → env → explicit)");
+    let toml_path = std::env::temp_dir().join("design_patterns_builder_demo.toml");
+    std::fs::write(
+        &toml_path,
+        "[database]\nhost = \"file.example.com\"\nport = 5432\nusername = \"file_user\"\npassword = \"file_pass\"\nssl = false\n",
+    )
+    .expect("failed to write demo config file");
+
+    std::env::set_var("MYAPP_HOST", "env.example.com");
+    std::env::set_var("MYAPP_CONNECTION_TIMEOUT", "45");
+
+    println!("host() runs before merge_env, yet provenance still beats the later env value:");
+    let layered_config = DatabaseConfigBuilder::from_toml(toml_path.to_str().unwrap())
+        .expect("failed to read demo config file")
+        .host("explicit.example.com") // explicit call, made before merge_env runs
+        .merge_env("MYAPP") // tries to override host from MYAPP_HOST - provenance blocks it
+        .port(6543) // explicit call wins over both file and env
+        .build_checked() // dynamic sources can't prove the typestate is Set
+        .unwrap();
+
+    println!("✅ Layered configuration: {:?}", layered_config);
+    println!("   (host stays explicit despite merge_env running after it, port explicit, username/password/ssl from file, connection_timeout from env)");
+
+    std::env::remove_var("MYAPP_HOST");
+    std::env::remove_var("MYAPP_CONNECTION_TIMEOUT");
+    let _ = std::fs::remove_file(&toml_path);
+
+    println!("\n📝 Example 5: Feeding the builder from untyped strings");
+    let from_strings = DatabaseConfigBuilder::new()
+        .set_str("host", "cli.example.com")
+        .and_then(|b| b.set_str("port", "7000"))
+        .and_then(|b| b.set_str("ssl", "true"))
+        .and_then(|b| b.set_str("username", "cli_user"))
+        .and_then(|b| b.set_str("password", "cli_pass"));
+
+    match from_strings {
+        Ok(builder) => println!("✅ Built from strings: {:?}", builder.build_checked().unwrap()),
+        Err(e) => println!("❌ Error: {e}"),
+    }
+
+    match DatabaseConfigBuilder::new().set_str("port", "not-a-number") {
+        Ok(_) => println!("✅ Parsed"),
+        Err(e) => println!("❌ Error: {e}"),
+    }
+
     println!("\n💡 Interview Points:");
-    println!("   • Method chaining with owned self");
-    println!("   • Validation using Result type");
-    println!("   • Optional fields with Default values");
+    println!("   • Typestate markers make a missing required field a compile error");
+    println!("   • Dynamic sources (env/TOML/set_str) fall back to build_checked()");
+    println!("   • Three-tier precedence: explicit > env > file, tracked per-field so call order can't break it");
+    println!("   • set_str parses untyped input via a data-driven Conversion");
     println!("   • No runtime overhead (zero-cost abstraction)");
 }
@@ -1,8 +1,44 @@
 // Chain of Responsibility Pattern Demo
 
+/// How serious a [`Diagnostic`] emitted by a handler is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A structured result from a handler: either a terminal decision or an
+/// annotation added while forwarding the request further down the chain.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub handled_by: String,
+    pub suggested_fix: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>, handled_by: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            message: message.into(),
+            handled_by: handled_by.into(),
+            suggested_fix: None,
+        }
+    }
+
+    pub fn with_fix(mut self, fix: impl Into<String>) -> Self {
+        self.suggested_fix = Some(fix.into());
+        self
+    }
+}
+
 pub trait Handler {
-    fn set_next(&mut self, next: Box<dyn Handler>);
-    fn handle(&self, request: &Request) -> Option<String>;
+    /// Either fully resolves `request` (a terminal `Diagnostic` is appended
+    /// and the chain stops), or annotates it and forwards to the next
+    /// handler, appending its own `Diagnostic` first either way.
+    fn handle(&self, request: &Request, trail: &mut Vec<Diagnostic>);
 }
 
 pub struct Request {
@@ -24,21 +60,31 @@ pub struct ManagerHandler {
 }
 
 impl ManagerHandler {
-    pub fn new() -> Self {
-        ManagerHandler { next: None }
+    pub fn new(next: Option<Box<dyn Handler>>) -> Self {
+        ManagerHandler { next }
     }
 }
 
 impl Handler for ManagerHandler {
-    fn set_next(&mut self, next: Box<dyn Handler>) {
-        self.next = Some(next);
-    }
-  
-    fn handle(&self, request: &Request) -> Option<String> {
+    fn handle(&self, request: &Request, trail: &mut Vec<Diagnostic>) {
         if request.amount <= 1000 {
-            Some(format!("✅ Manager approved: {}", request.description))
+            trail.push(Diagnostic::new(
+                Severity::Info,
+                format!("approved: {}", request.description),
+                "Manager",
+            ));
         } else {
-            self.next.as_ref()?.handle(request)
+            trail.push(
+                Diagnostic::new(
+                    Severity::Warning,
+                    format!("amount ${} exceeds manager limit, forwarding", request.amount),
+                    "Manager",
+                )
+                .with_fix("escalate to Director".to_string()),
+            );
+            if let Some(next) = &self.next {
+                next.handle(request, trail);
+            }
         }
     }
 }
@@ -48,21 +94,31 @@ pub struct DirectorHandler {
 }
 
 impl DirectorHandler {
-    pub fn new() -> Self {
-        DirectorHandler { next: None }
+    pub fn new(next: Option<Box<dyn Handler>>) -> Self {
+        DirectorHandler { next }
     }
 }
 
 impl Handler for DirectorHandler {
-    fn set_next(&mut self, next: Box<dyn Handler>) {
-        self.next = Some(next);
-    }
-  
-    fn handle(&self, request: &Request) -> Option<String> {
+    fn handle(&self, request: &Request, trail: &mut Vec<Diagnostic>) {
         if request.amount <= 10000 {
-            Some(format!("✅ Director approved: {}", request.description))
+            trail.push(Diagnostic::new(
+                Severity::Info,
+                format!("approved: {}", request.description),
+                "Director",
+            ));
         } else {
-            self.next.as_ref()?.handle(request)
+            trail.push(
+                Diagnostic::new(
+                    Severity::Warning,
+                    format!("amount ${} exceeds director limit, forwarding", request.amount),
+                    "Director",
+                )
+                .with_fix("escalate to CEO".to_string()),
+            );
+            if let Some(next) = &self.next {
+                next.handle(request, trail);
+            }
         }
     }
 }
@@ -76,52 +132,107 @@ impl CEOHandler {
 }
 
 impl Handler for CEOHandler {
-    fn set_next(&mut self, _next: Box<dyn Handler>) {
-        // CEO is the last in chain
-    }
-  
-    fn handle(&self, request: &Request) -> Option<String> {
+    fn handle(&self, request: &Request, trail: &mut Vec<Diagnostic>) {
         if request.amount <= 100000 {
-            Some(format!("✅ CEO approved: {}", request.description))
+            trail.push(Diagnostic::new(
+                Severity::Info,
+                format!("approved: {}", request.description),
+                "CEO",
+            ));
         } else {
-            Some(format!("❌ Request rejected: amount too large"))
+            trail.push(
+                Diagnostic::new(
+                    Severity::Error,
+                    format!("amount ${} exceeds company approval limit", request.amount),
+                    "CEO",
+                )
+                .with_fix("split the request or seek board approval".to_string()),
+            );
         }
     }
 }
 
+/// A handler constructor: given the already-built tail of the chain (or
+/// `None` for the last handler), produces the next handler wrapping it.
+type HandlerCtor = Box<dyn Fn(Option<Box<dyn Handler>>) -> Box<dyn Handler>>;
+
+/// Builds an ordered chain from a list of handlers, solving the ownership
+/// wart where a `CEOHandler` (or anything else) couldn't be wired in after
+/// construction: handlers are linked front-to-back at build time instead of
+/// via a separate `set_next` call.
+pub struct ChainBuilder {
+    handlers: Vec<HandlerCtor>,
+}
+
+impl ChainBuilder {
+    pub fn new() -> Self {
+        ChainBuilder {
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Appends a handler constructor to the chain; `next` is threaded in
+    /// automatically when `build()` links everything together.
+    pub fn add(mut self, ctor: impl Fn(Option<Box<dyn Handler>>) -> Box<dyn Handler> + 'static) -> Self {
+        self.handlers.push(Box::new(ctor));
+        self
+    }
+
+    /// Links every registered handler in order and returns the head of the
+    /// chain.
+    pub fn build(self) -> Option<Box<dyn Handler>> {
+        let mut next: Option<Box<dyn Handler>> = None;
+        for ctor in self.handlers.into_iter().rev() {
+            next = Some(ctor(next));
+        }
+        next
+    }
+}
+
 pub fn demo_chain_of_responsibility() {
     println!("🔗 CHAIN OF RESPONSIBILITY DEMO");
     println!("{}", "=".repeat(60));
     println!("\nThis pattern passes requests along a chain of handlers.");
     println!("Rust Benefit: Dynamic dispatch with trait objects.");
-    
-    println!("\n📝 Example 1: Approval chain");
-    
-    let mut manager = ManagerHandler::new();
-    let director = DirectorHandler::new();
-    let _ceo = CEOHandler::new();
-    
-    manager.set_next(Box::new(director));
-    
-    // This will need to be implemented differently due to ownership
+
+    println!("\n📝 Example 1: Approval chain with structured diagnostics");
+
+    let chain = ChainBuilder::new()
+        .add(|next| Box::new(ManagerHandler::new(next)))
+        .add(|next| Box::new(DirectorHandler::new(next)))
+        .add(|_next| Box::new(CEOHandler::new()))
+        .build()
+        .expect("chain should not be empty");
+
     let requests = vec![
         Request::new(500, "Office supplies"),
         Request::new(5000, "Equipment upgrade"),
         Request::new(50000, "Infrastructure project"),
         Request::new(200000, "Acquisition"),
     ];
-    
+
     println!("Processing requests through approval chain:");
     for request in &requests {
         println!("\nRequest: {} - ${}", request.description, request.amount);
-        if let Some(result) = manager.handle(request) {
-            println!("Result: {}", result);
+        let mut trail = Vec::new();
+        chain.handle(request, &mut trail);
+        for diagnostic in &trail {
+            let icon = match diagnostic.severity {
+                Severity::Info => "✅",
+                Severity::Warning => "⚠️",
+                Severity::Error => "❌",
+            };
+            println!("   {icon} [{}] {}", diagnostic.handled_by, diagnostic.message);
+            if let Some(fix) = &diagnostic.suggested_fix {
+                println!("      💡 suggested fix: {fix}");
+            }
         }
     }
-    
+
     println!("\n💡 Interview Points:");
     println!("   • Avoid coupling sender and receiver");
     println!("   • Chain processing requests");
-    println!("   • Multiple handlers process request");
+    println!("   • Handlers resolve or annotate-and-forward with structured diagnostics");
+    println!("   • ChainBuilder links handlers of arbitrary length cleanly");
     println!("   • Use case: middleware, filters, validators");
 }
@@ -1,6 +1,7 @@
 // Command Pattern Demo
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub trait Command {
     fn execute(&self);
@@ -76,6 +77,62 @@ impl Command for TurnOffCommand {
     }
 }
 
+/// A composite command: bundles several commands and itself implements
+/// `Command`, so a single "Scene" button can undo atomically as a unit -
+/// the recursive-composition angle of the Command pattern that a flat
+/// `Vec<Box<dyn Command>>` on its own can't express.
+pub struct MacroCommand {
+    name: String,
+    commands: Vec<Box<dyn Command>>,
+}
+
+impl MacroCommand {
+    pub fn new(name: &str, commands: Vec<Box<dyn Command>>) -> Self {
+        MacroCommand {
+            name: name.to_string(),
+            commands,
+        }
+    }
+}
+
+impl Command for MacroCommand {
+    fn execute(&self) {
+        for command in &self.commands {
+            command.execute();
+        }
+    }
+
+    fn undo(&self) {
+        for command in self.commands.iter().rev() {
+            command.undo();
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Whether a [`JournalEntry`] recorded an execute or an undo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalEntryKind {
+    Execute,
+    Undo,
+}
+
+/// One recorded step in a `CommandHistory`'s history, enough to replay the
+/// session later: which command ran, when, and whether it was executed or
+/// undone.
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    pub name: String,
+    pub timestamp: SystemTime,
+    pub kind: JournalEntryKind,
+}
+
+/// The classic fixed-script remote: queue up commands, then run or unwind
+/// the whole queue. Has no notion of a cursor or partial undo - for that,
+/// use [`CommandHistory`].
 pub struct RemoteControl {
     commands: Vec<Box<dyn Command>>,
 }
@@ -86,18 +143,18 @@ impl RemoteControl {
             commands: Vec::new(),
         }
     }
-  
+
     pub fn add_command(&mut self, command: Box<dyn Command>) {
         println!("Added command: {}", command.name());
         self.commands.push(command);
     }
-  
+
     pub fn execute_all(&self) {
         for command in &self.commands {
             command.execute();
         }
     }
-    
+
     pub fn undo_all(&self) {
         for command in self.commands.iter().rev() {
             command.undo();
@@ -105,6 +162,130 @@ impl RemoteControl {
     }
 }
 
+/// A cursor-based undo/redo history, kept as its own type rather than bolted
+/// onto [`RemoteControl`]: `RemoteControl::add_command` never advances a
+/// cursor, so mixing the two APIs over one `Vec<Box<dyn Command>>` let
+/// `execute`'s `truncate(self.cursor)` silently discard commands that
+/// `add_command` had queued. `CommandHistory` only exposes the cursor-aware
+/// operations, so that invariant can't be violated from outside.
+pub struct CommandHistory {
+    commands: Vec<Box<dyn Command>>,
+    /// How many commands in `commands`, from the front, are currently
+    /// applied. Everything at or past `cursor` is the redo tail.
+    cursor: usize,
+    journal: Vec<JournalEntry>,
+}
+
+impl CommandHistory {
+    pub fn new() -> Self {
+        CommandHistory {
+            commands: Vec::new(),
+            cursor: 0,
+            journal: Vec::new(),
+        }
+    }
+
+    /// Runs `command`, pushes it onto the history, and discards any
+    /// commands past the current cursor (the redo tail), matching the
+    /// usual undo/redo semantics of editors and games.
+    pub fn execute(&mut self, command: Box<dyn Command>) {
+        command.execute();
+        self.journal.push(JournalEntry {
+            name: command.name().to_string(),
+            timestamp: SystemTime::now(),
+            kind: JournalEntryKind::Execute,
+        });
+        self.commands.truncate(self.cursor);
+        self.commands.push(command);
+        self.cursor += 1;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.cursor < self.commands.len()
+    }
+
+    /// Steps the cursor back one and undoes that command.
+    pub fn undo(&mut self) -> Result<(), &'static str> {
+        if !self.can_undo() {
+            return Err("nothing left to undo");
+        }
+        self.cursor -= 1;
+        self.commands[self.cursor].undo();
+        self.journal.push(JournalEntry {
+            name: self.commands[self.cursor].name().to_string(),
+            timestamp: SystemTime::now(),
+            kind: JournalEntryKind::Undo,
+        });
+        Ok(())
+    }
+
+    /// Steps the cursor forward one and re-executes that command.
+    pub fn redo(&mut self) -> Result<(), &'static str> {
+        if !self.can_redo() {
+            return Err("nothing left to redo");
+        }
+        self.commands[self.cursor].execute();
+        self.journal.push(JournalEntry {
+            name: self.commands[self.cursor].name().to_string(),
+            timestamp: SystemTime::now(),
+            kind: JournalEntryKind::Execute,
+        });
+        self.cursor += 1;
+        Ok(())
+    }
+
+    pub fn journal_entries(&self) -> &[JournalEntry] {
+        &self.journal
+    }
+
+    /// Serializes the recorded journal to TOML: one `[[entry]]` table per
+    /// recorded step. This demo keeps a minimal hand-rolled serializer
+    /// instead of depending on a TOML crate.
+    pub fn export_journal(&self) -> String {
+        let mut out = String::new();
+        for entry in &self.journal {
+            let secs = entry
+                .timestamp
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let kind = match entry.kind {
+                JournalEntryKind::Execute => "Execute",
+                JournalEntryKind::Undo => "Undo",
+            };
+            out.push_str("[[entry]]\n");
+            out.push_str(&format!("name = \"{}\"\n", entry.name));
+            out.push_str(&format!("timestamp = {secs}\n"));
+            out.push_str(&format!("kind = \"{kind}\"\n\n"));
+        }
+        out
+    }
+
+    /// Reconstructs and re-executes a recorded sequence of commands against
+    /// a freshly built target. `factory` maps a command name back to a
+    /// fresh `Command` bound to whatever target the caller supplies, which
+    /// lets a crashed session's journal be replayed deterministically.
+    pub fn replay_journal(
+        entries: &[JournalEntry],
+        factory: &dyn Fn(&str) -> Option<Box<dyn Command>>,
+    ) {
+        for entry in entries {
+            let Some(command) = factory(&entry.name) else {
+                println!("⚠️  No constructor registered for command '{}', skipping", entry.name);
+                continue;
+            };
+            match entry.kind {
+                JournalEntryKind::Execute => command.execute(),
+                JournalEntryKind::Undo => command.undo(),
+            }
+        }
+    }
+}
+
 pub fn demo_command() {
     println!("📝 COMMAND PATTERN DEMO");
     println!("{}", "=".repeat(60));
@@ -113,20 +294,73 @@ pub fn demo_command() {
     
     println!("\n📝 Example 1: Undo/Redo operations");
     let light = Rc::new(RefCell::new(Light::new()));
-    
+
     let mut remote = RemoteControl::new();
     remote.add_command(Box::new(TurnOnCommand::new(light.clone())));
     remote.add_command(Box::new(TurnOffCommand::new(light.clone())));
-    
+
     println!("\nExecuting all commands:");
     remote.execute_all();
-    
+
     println!("\nUndoing all commands:");
     remote.undo_all();
-    
+
+    println!("\n📝 Example 2: Cursor-based history with redo tail discard");
+    let mut history = CommandHistory::new();
+    history.execute(Box::new(TurnOnCommand::new(light.clone())));
+    history.execute(Box::new(TurnOffCommand::new(light.clone())));
+
+    println!("\nUndoing once:");
+    history.undo().unwrap();
+
+    println!("\nExecuting a new command (discards the redo tail):");
+    history.execute(Box::new(TurnOnCommand::new(light.clone())));
+
+    println!("\nTrying to redo after the tail was discarded:");
+    match history.redo() {
+        Ok(()) => println!("Redo succeeded"),
+        Err(e) => println!("❌ {e}"),
+    }
+
+    println!("\n📝 Example 3: Journaling and replaying a session");
+    let journal = history.export_journal();
+    println!("Exported journal:\n{journal}");
+
+    println!("Replaying the journal against a fresh light:");
+    let replay_light = Rc::new(RefCell::new(Light::new()));
+    let replay_light_for_factory = replay_light.clone();
+    let factory = move |name: &str| -> Option<Box<dyn Command>> {
+        match name {
+            "Turn On" => Some(Box::new(TurnOnCommand::new(replay_light_for_factory.clone()))),
+            "Turn Off" => Some(Box::new(TurnOffCommand::new(replay_light_for_factory.clone()))),
+            _ => None,
+        }
+    };
+    CommandHistory::replay_journal(history.journal_entries(), &factory);
+
+    println!("\n📝 Example 4: Macro/composite command (a \"Scene\" button)");
+    let living_room = Rc::new(RefCell::new(Light::new()));
+    let kitchen = Rc::new(RefCell::new(Light::new()));
+    let movie_night_scene = MacroCommand::new(
+        "Movie Night Scene",
+        vec![
+            Box::new(TurnOnCommand::new(living_room.clone())),
+            Box::new(TurnOffCommand::new(kitchen.clone())),
+        ],
+    );
+
+    let mut scene_history = CommandHistory::new();
+    println!("\nRunning the scene:");
+    scene_history.execute(Box::new(movie_night_scene));
+
+    println!("\nUndoing the scene atomically:");
+    scene_history.undo().unwrap();
+
     println!("\n💡 Interview Points:");
     println!("   • Encapsulate requests as objects");
     println!("   • Parameterize clients with different requests");
-    println!("   • Undo/redo support");
-    println!("   • Queue and log operations");
+    println!("   • Cursor-based history: execute truncates the redo tail");
+    println!("   • can_undo()/can_redo() guard boundary conditions");
+    println!("   • Queue and log operations: export/replay a journal for crash recovery and audit");
+    println!("   • MacroCommand composes commands recursively, undoing as one unit");
 }
@@ -0,0 +1,98 @@
+// Shared type-coercion core used by the Builder (`set_str`) and Template
+// Method (`transform_data`) demos. Both patterns need to turn an untyped
+// string into a concrete, typed value from the same small set of
+// conversions; this module holds that one implementation so a fix (like
+// `TimestampFmt` actually honoring its captured format) can't happen in one
+// copy and not the other. Each caller keeps its own domain-specific error
+// type - constructing one from `Conversion::parse`'s `Option` is a one-liner.
+use std::str::FromStr;
+
+/// A named type coercion, parsed from a short spec like `"int"` or
+/// `"ts|%Y-%m-%d"`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conversion {
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    /// A timestamp with an explicit `strftime`-style format string.
+    TimestampFmt(String),
+}
+
+impl FromStr for Conversion {
+    /// The spec string that failed to parse, for the caller to wrap in its
+    /// own error type.
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((kind, fmt)) = s.split_once('|') {
+            if kind == "ts" || kind == "timestamp" {
+                return Ok(Conversion::TimestampFmt(fmt.to_string()));
+            }
+            return Err(s.to_string());
+        }
+
+        match s {
+            "bytes" | "string" => Ok(Conversion::Bytes),
+            "int" | "integer" => Ok(Conversion::Integer),
+            "float" => Ok(Conversion::Float),
+            "bool" | "boolean" => Ok(Conversion::Boolean),
+            "ts" | "timestamp" => Ok(Conversion::Timestamp),
+            other => Err(other.to_string()),
+        }
+    }
+}
+
+/// A value coerced into a concrete type by a [`Conversion`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedValue {
+    Bytes(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    /// Seconds since the Unix epoch.
+    Timestamp(u64),
+}
+
+impl Conversion {
+    /// Trims `raw` and parses it into the concrete [`TypedValue`] this
+    /// conversion describes, or `None` on failure. Callers wrap `None` in
+    /// whatever error type fits their own diagnostics.
+    pub fn parse(&self, raw: &str) -> Option<TypedValue> {
+        let raw = raw.trim();
+        match self {
+            Conversion::Bytes => Some(TypedValue::Bytes(raw.to_string())),
+            Conversion::Integer => raw.parse::<i64>().ok().map(TypedValue::Integer),
+            Conversion::Float => raw.parse::<f64>().ok().map(TypedValue::Float),
+            Conversion::Boolean => match raw.to_lowercase().as_str() {
+                "true" | "1" | "yes" => Some(TypedValue::Boolean(true)),
+                "false" | "0" | "no" => Some(TypedValue::Boolean(false)),
+                _ => None,
+            },
+            Conversion::Timestamp => raw.parse::<u64>().ok().map(TypedValue::Timestamp),
+            Conversion::TimestampFmt(fmt) => {
+                // This demo keeps its own parsing free of a date/time crate;
+                // it only understands the literal `%Y-%m-%d` layout.
+                if fmt == "%Y-%m-%d" {
+                    parse_ymd(raw)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn parse_ymd(raw: &str) -> Option<TypedValue> {
+    let mut parts = raw.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // Rough days-since-epoch math is plenty for a demo; not calendar-exact.
+    let days_since_epoch = (year - 1970) * 365 + (month - 1) * 30 + (day - 1);
+    Some(TypedValue::Timestamp((days_since_epoch * 86_400).max(0) as u64))
+}
@@ -0,0 +1,34 @@
+// Shared minimal executor used by the Strategy and Proxy demos' async paths.
+// Both need just enough of an executor to drive a handful of futures without
+// pulling in an async runtime dependency; this module holds that one
+// implementation so it isn't copy-pasted between the two.
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::thread;
+use std::time::Duration;
+
+fn noop_raw_waker() -> RawWaker {
+    fn no_op(_: *const ()) {}
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    let vtable = &RawWakerVTable::new(clone, no_op, no_op, no_op);
+    RawWaker::new(std::ptr::null(), vtable)
+}
+
+/// Minimal single-threaded executor for this crate's dependency-free demos:
+/// busy-polls `future` until it's ready. Good enough for the short-lived
+/// futures used here; not a general-purpose runtime.
+pub fn block_on<F: Future>(mut future: F) -> F::Output {
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    // SAFETY: `future` is not moved again after being pinned here.
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+}
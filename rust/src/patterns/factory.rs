@@ -1,4 +1,5 @@
 // Factory Pattern Demo
+use std::collections::HashMap;
 
 pub trait Animal {
     fn make_sound(&self);
@@ -64,74 +65,138 @@ impl Animal for Cat {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum AnimalType {
-    Dog,
-    Cat,
-    Bird,
+#[derive(Debug)]
+pub struct Bird {
+    name: String,
+    weight: f32,
+}
+
+impl Bird {
+    pub fn new(name: &str, weight: f32) -> Self {
+        Bird {
+            name: name.to_string(),
+            weight,
+        }
+    }
+}
+
+impl Animal for Bird {
+    fn make_sound(&self) {
+        println!("{} says: Tweet!", self.name);
+    }
+
+    fn get_species(&self) -> String {
+        "Aves".to_string()
+    }
+
+    fn get_weight(&self) -> f32 {
+        self.weight
+    }
 }
 
-pub struct AnimalFactory;
+type AnimalConstructor = Box<dyn Fn(&str, f32) -> Result<Box<dyn Animal>, String>>;
+
+/// A registry-based factory: species are registered at runtime instead of
+/// being baked into a fixed enum, so adding a new kind of animal never
+/// requires touching this crate.
+pub struct AnimalFactory {
+    constructors: HashMap<String, AnimalConstructor>,
+}
 
 impl AnimalFactory {
-    pub fn create_animal(animal_type: AnimalType, name: &str, weight: f32) -> Result<Box<dyn Animal>, String> {
-        match animal_type {
-            AnimalType::Dog => {
-                if weight < 1.0 || weight > 100.0 {
-                    return Err("Dog weight must be between 1.0 and 100.0 kg".to_string());
-                }
-                Ok(Box::new(Dog::new(name, weight)))
-            },
-            AnimalType::Cat => {
-                if weight < 0.5 || weight > 20.0 {
-                    return Err("Cat weight must be between 0.5 and 20.0 kg".to_string());
-                }
-                Ok(Box::new(Cat::new(name, weight)))
-            },
-            AnimalType::Bird => {
-                Err("Bird implementation not yet available".to_string())
-            }
+    pub fn new() -> Self {
+        AnimalFactory {
+            constructors: HashMap::new(),
         }
     }
-    
-    pub fn create_dog(name: &str) -> Box<dyn Animal> {
-        Box::new(Dog::new(name, 25.0)) // Average dog weight
+
+    pub fn register(
+        &mut self,
+        species: &str,
+        ctor: impl Fn(&str, f32) -> Result<Box<dyn Animal>, String> + 'static,
+    ) {
+        self.constructors.insert(species.to_string(), Box::new(ctor));
     }
-    
-    pub fn create_cat(name: &str) -> Box<dyn Animal> {
-        Box::new(Cat::new(name, 4.5)) // Average cat weight
+
+    pub fn create(
+        &self,
+        species: &str,
+        name: &str,
+        weight: f32,
+    ) -> Result<Box<dyn Animal>, String> {
+        let ctor = self
+            .constructors
+            .get(species)
+            .ok_or_else(|| format!("No constructor registered for species '{species}'"))?;
+        ctor(name, weight)
     }
 }
 
+/// A factory pre-populated with the species this crate ships out of the box.
+pub fn default_registry() -> AnimalFactory {
+    let mut factory = AnimalFactory::new();
+
+    factory.register("dog", |name, weight| {
+        if weight < 1.0 || weight > 100.0 {
+            return Err("Dog weight must be between 1.0 and 100.0 kg".to_string());
+        }
+        Ok(Box::new(Dog::new(name, weight)))
+    });
+
+    factory.register("cat", |name, weight| {
+        if weight < 0.5 || weight > 20.0 {
+            return Err("Cat weight must be between 0.5 and 20.0 kg".to_string());
+        }
+        Ok(Box::new(Cat::new(name, weight)))
+    });
+
+    factory
+}
+
 pub fn demo_factory() {
     println!("🏭 FACTORY PATTERN DEMO");
     println!("{}", "=".repeat(60));
     println!("\nThis pattern creates objects without specifying exact classes.");
     println!("Rust Benefit: Trait objects for dynamic dispatch.");
-    
-    println!("\n📝 Example 1: Creating animals with factory");
+
+    println!("\n📝 Example 1: Creating animals with the default registry");
+    let mut factory = default_registry();
     let animals: Vec<Box<dyn Animal>> = vec![
-        AnimalFactory::create_animal(AnimalType::Dog, "Buddy", 30.0).unwrap(),
-        AnimalFactory::create_animal(AnimalType::Cat, "Whiskers", 5.0).unwrap(),
-        AnimalFactory::create_dog("Max"),
-        AnimalFactory::create_cat("Luna"),
+        factory.create("dog", "Buddy", 30.0).unwrap(),
+        factory.create("cat", "Whiskers", 5.0).unwrap(),
     ];
-    
+
     for animal in animals {
         animal.make_sound();
         println!("   Species: {}", animal.get_species());
         println!("   Weight: {:.1} kg\n", animal.get_weight());
     }
-    
+
     println!("\n📝 Example 2: Error handling with invalid parameters");
-    match AnimalFactory::create_animal(AnimalType::Dog, "Tiny", 0.5) {
+    match factory.create("dog", "Tiny", 0.5) {
         Ok(_) => println!("✅ Animal created"),
         Err(e) => println!("❌ Error: {}", e),
     }
-    
+
+    println!("\n📝 Example 3: Registering a new species at runtime");
+    factory.register("bird", |name, weight| {
+        if weight <= 0.0 || weight > 5.0 {
+            return Err("Bird weight must be between 0.0 and 5.0 kg".to_string());
+        }
+        Ok(Box::new(Bird::new(name, weight)))
+    });
+    let bird = factory.create("bird", "Tweety", 0.2).unwrap();
+    bird.make_sound();
+    println!("   Species: {}", bird.get_species());
+
+    match factory.create("dragon", "Smaug", 1000.0) {
+        Ok(_) => println!("✅ Animal created"),
+        Err(e) => println!("❌ Error: {}", e),
+    }
+
     println!("\n💡 Interview Points:");
     println!("   • Trait objects for runtime polymorphism");
-    println!("   • Enum-based factory selection");
+    println!("   • Registry-based factory keeps the type open/closed");
     println!("   • Error handling with Result type");
     println!("   • Memory safety with Box");
 }
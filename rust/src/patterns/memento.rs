@@ -1,71 +1,159 @@
 // Memento Pattern Demo
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
 
-pub struct Memento {
-    state: String,
+/// A snapshot of an `Originator<T>`'s state at some point in time.
+#[derive(Debug, Clone)]
+pub struct Memento<T: Clone> {
+    state: T,
 }
 
-impl Memento {
-    pub fn new(state: &str) -> Self {
-        Memento {
-            state: state.to_string(),
-        }
+impl<T: Clone> Memento<T> {
+    pub fn new(state: T) -> Self {
+        Memento { state }
     }
-  
-    pub fn get_state(&self) -> &str {
+
+    pub fn get_state(&self) -> &T {
         &self.state
     }
 }
 
-pub struct Originator {
-    state: String,
+pub struct Originator<T: Clone> {
+    state: T,
 }
 
-impl Originator {
-    pub fn new(state: &str) -> Self {
-        Originator {
-            state: state.to_string(),
-        }
+impl<T: Clone> Originator<T> {
+    pub fn new(state: T) -> Self {
+        Originator { state }
     }
-  
-    pub fn set_state(&mut self, state: &str) {
-        self.state = state.to_string();
+
+    pub fn set_state(&mut self, state: T) {
+        self.state = state;
     }
-  
-    pub fn get_state(&self) -> &str {
+
+    pub fn get_state(&self) -> &T {
         &self.state
     }
-  
-    pub fn create_memento(&self) -> Memento {
-        Memento::new(&self.state)
+
+    pub fn create_memento(&self) -> Memento<T> {
+        Memento::new(self.state.clone())
     }
-  
-    pub fn restore_from_memento(&mut self, memento: &Memento) {
-        self.state = memento.get_state().to_string();
+
+    pub fn restore_from_memento(&mut self, memento: &Memento<T>) {
+        self.state = memento.get_state().clone();
     }
 }
 
-pub struct Caretaker {
-    mementos: Vec<Memento>,
+/// An undo/redo history built from two stacks, with a `max_depth` cap that
+/// evicts the oldest snapshot once exceeded (the same ring-buffer eviction
+/// the Logger's `max_logs` uses).
+pub struct Caretaker<T: Clone> {
+    undo_stack: Vec<Memento<T>>,
+    redo_stack: Vec<Memento<T>>,
+    max_depth: usize,
 }
 
-impl Caretaker {
-    pub fn new() -> Self {
+impl<T: Clone> Caretaker<T> {
+    pub fn new(max_depth: usize) -> Self {
         Caretaker {
-            mementos: Vec::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            max_depth,
         }
     }
-  
-    pub fn add_memento(&mut self, memento: Memento) {
+
+    /// Records a new save point. Taking a new snapshot clears the redo
+    /// stack, since the previous "future" history is no longer valid.
+    pub fn save(&mut self, originator: &Originator<T>) {
         println!("📸 Save point created");
-        self.mementos.push(memento);
+        self.undo_stack.push(originator.create_memento());
+        self.redo_stack.clear();
+
+        while self.undo_stack.len() > self.max_depth {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    /// Steps back one save point, if any, restoring `originator` to it.
+    pub fn undo(&mut self, originator: &mut Originator<T>) -> Result<(), &'static str> {
+        let memento = self.undo_stack.pop().ok_or("nothing left to undo")?;
+        self.redo_stack.push(originator.create_memento());
+        originator.restore_from_memento(&memento);
+        Ok(())
+    }
+
+    /// Steps forward one save point, if any, restoring `originator` to it.
+    pub fn redo(&mut self, originator: &mut Originator<T>) -> Result<(), &'static str> {
+        let memento = self.redo_stack.pop().ok_or("nothing left to redo")?;
+        self.undo_stack.push(originator.create_memento());
+        originator.restore_from_memento(&memento);
+        Ok(())
+    }
+
+    pub fn save_count(&self) -> usize {
+        self.undo_stack.len()
     }
-  
-    pub fn get_memento(&self, index: usize) -> Option<&Memento> {
-        self.mementos.get(index)
+}
+
+impl<T> Caretaker<T>
+where
+    T: Clone + fmt::Display + FromStr,
+{
+    /// Serializes the undo-stack history to `path`: each snapshot is written
+    /// as its byte length, a newline, then that many raw bytes of `Display`
+    /// output. Length-prefixing (rather than one snapshot per line) keeps
+    /// this correct even when a snapshot's rendering itself contains `\n`.
+    pub fn save_to_path(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut contents = String::new();
+        for memento in &self.undo_stack {
+            let state = memento.get_state().to_string();
+            contents.push_str(&state.len().to_string());
+            contents.push('\n');
+            contents.push_str(&state);
+            contents.push('\n');
+        }
+        fs::write(path, contents)
     }
-    
-    pub fn get_save_count(&self) -> usize {
-        self.mementos.len()
+
+    /// Restores an undo-stack history previously written by `save_to_path`.
+    /// The redo stack starts empty. `max_depth` is not stored in the file:
+    /// the new caretaker takes it from the `max_depth` argument, and the
+    /// loaded history is not trimmed to it.
+    pub fn load_from_path(path: impl AsRef<Path>, max_depth: usize) -> io::Result<Caretaker<T>> {
+        let contents = fs::read(path)?;
+        let mut undo_stack = Vec::new();
+        let mut offset = 0;
+        while offset < contents.len() {
+            let nl = contents[offset..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "truncated snapshot length"))?;
+            let len: usize = std::str::from_utf8(&contents[offset..offset + nl])
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid snapshot length"))?;
+            offset += nl + 1;
+
+            let state_bytes = contents
+                .get(offset..offset + len)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "truncated snapshot"))?;
+            let state_str = std::str::from_utf8(state_bytes)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "snapshot is not valid UTF-8"))?;
+            let state = state_str
+                .parse::<T>()
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "failed to parse snapshot"))?;
+            undo_stack.push(Memento::new(state));
+
+            offset += len + 1; // skip the trailing newline separator
+        }
+        Ok(Caretaker {
+            undo_stack,
+            redo_stack: Vec::new(),
+            max_depth,
+        })
     }
 }
 
@@ -74,34 +162,46 @@ pub fn demo_memento() {
     println!("{}", "=".repeat(60));
     println!("\nThis pattern captures and restores object state.");
     println!("Rust Benefit: Safe state management with immutability.");
-    
-    println!("\n📝 Example 1: Game save/restore system");
-    let mut game_state = Originator::new("Level 1 - Village");
-    let mut caretaker = Caretaker::new();
-    
+
+    println!("\n📝 Example 1: Game save/restore system with undo/redo");
+    let mut game_state = Originator::new("Level 1 - Village".to_string());
+    let mut caretaker: Caretaker<String> = Caretaker::new(10);
+
     println!("Initial state: {}", game_state.get_state());
-    
-    // Save checkpoint 1
-    caretaker.add_memento(game_state.create_memento());
-    
-    game_state.set_state("Level 2 - Forest");
+
+    caretaker.save(&game_state);
+    game_state.set_state("Level 2 - Forest".to_string());
     println!("New state: {}", game_state.get_state());
-    
-    // Save checkpoint 2
-    caretaker.add_memento(game_state.create_memento());
-    
-    game_state.set_state("Level 3 - Castle");
+
+    caretaker.save(&game_state);
+    game_state.set_state("Level 3 - Castle".to_string());
     println!("New state: {}", game_state.get_state());
-    
-    println!("\nRestoring from checkpoint 1:");
-    if let Some(memento) = caretaker.get_memento(0) {
-        game_state.restore_from_memento(memento);
-        println!("Restored state: {}", game_state.get_state());
-    }
-    
+
+    println!("\nUndoing once:");
+    caretaker.undo(&mut game_state).unwrap();
+    println!("State: {}", game_state.get_state());
+
+    println!("\nUndoing again:");
+    caretaker.undo(&mut game_state).unwrap();
+    println!("State: {}", game_state.get_state());
+
+    println!("\nRedoing once:");
+    caretaker.redo(&mut game_state).unwrap();
+    println!("State: {}", game_state.get_state());
+
+    println!("\n📝 Example 2: Persisting the timeline to disk");
+    let save_path = std::env::temp_dir().join("design_patterns_memento_demo.save");
+    caretaker.save_to_path(&save_path).expect("failed to write save file");
+    println!("Saved {} snapshot(s) to {}", caretaker.save_count(), save_path.display());
+
+    let restored: Caretaker<String> =
+        Caretaker::load_from_path(&save_path, 10).expect("failed to read save file");
+    println!("Restored {} snapshot(s) from disk", restored.save_count());
+    let _ = fs::remove_file(&save_path);
+
     println!("\n💡 Interview Points:");
     println!("   • Capture object state without violating encapsulation");
-    println!("   • Undo/redo functionality");
-    println!("   • Game save systems");
-    println!("   • Transaction rollback");
+    println!("   • Undo/redo via two stacks, redo cleared on a new save");
+    println!("   • max_depth caps memory use like a ring buffer");
+    println!("   • Save points can persist to disk across runs");
 }
@@ -1,5 +1,7 @@
 // Pattern module declarations
 pub mod builder;
+pub mod conversion;
+pub mod executor;
 pub mod factory;
 pub mod singleton;
 pub mod observer;
@@ -1,5 +1,6 @@
 // Observer Pattern Demo
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug, Clone)]
@@ -19,6 +20,10 @@ pub struct NewsSubject {
     observers: Vec<Weak<RefCell<dyn Observer>>>,
     events: Vec<NewsEvent>,
     next_id: u32,
+    /// Events waiting to be drained by `poll_for_event`, for callers that
+    /// want to drive delivery from their own loop instead of notifying
+    /// synchronously inside `publish_news`.
+    queue: VecDeque<NewsEvent>,
 }
 
 impl NewsSubject {
@@ -27,6 +32,7 @@ impl NewsSubject {
             observers: Vec::new(),
             events: Vec::new(),
             next_id: 1,
+            queue: VecDeque::new(),
         }
     }
     
@@ -47,14 +53,24 @@ impl NewsSubject {
     
     pub fn notify(&self, event: &NewsEvent) {
         println!("Notifying {} observers about: {}", self.observers.len(), event.title);
-        
+
         for weak_observer in &self.observers {
             if let Some(observer) = weak_observer.upgrade() {
                 observer.borrow().update(event);
             }
         }
     }
-    
+
+    /// Delivers `event` to every attached observer. Identical to `notify`,
+    /// named to read naturally alongside `poll_for_event` in a reactor-style
+    /// `while let Some(ev) = subject.poll_for_event() { subject.dispatch(&ev) }`.
+    pub fn dispatch(&self, event: &NewsEvent) {
+        self.notify(event);
+    }
+
+    /// Queues a news event instead of delivering it inline, so callers can
+    /// interleave delivery with their own event loop (timers, I/O, etc.)
+    /// instead of paying for recursive synchronous notification.
     pub fn publish_news(&mut self, title: &str, content: &str, category: &str) {
         let event = NewsEvent {
             id: self.next_id,
@@ -62,10 +78,27 @@ impl NewsSubject {
             content: content.to_string(),
             category: category.to_string(),
         };
-        
+
         self.next_id += 1;
         self.events.push(event.clone());
-        self.notify(&event);
+        self.queue.push_back(event);
+    }
+
+    /// Drains one queued event, if any, for the caller to dispatch.
+    pub fn poll_for_event(&mut self) -> Option<NewsEvent> {
+        self.queue.pop_front()
+    }
+
+    /// Number of events still waiting to be polled.
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Convenience: drains and dispatches every queued event in order.
+    pub fn dispatch_all(&mut self) {
+        while let Some(event) = self.poll_for_event() {
+            self.dispatch(&event);
+        }
     }
 }
 
@@ -150,37 +183,46 @@ pub fn demo_observer() {
     
     news_subject.attach(email_notifier.clone());
     news_subject.attach(sms_sender.clone());
-    
+
     news_subject.publish_news(
         "New AI Breakthrough",
         "Scientists develop new AI model...",
         "Technology"
     );
-    
+
     news_subject.publish_news(
         "Breaking: Earthquake Alert",
         "Earthquake detected in region...",
         "Breaking"
     );
-    
+
     news_subject.publish_news(
         "Weather Update",
         "Sunny weather expected...",
         "Weather"
     );
-    
-    println!("\n📝 Example 2: Detaching observer");
+
+    println!("\n📝 Example 2: Poll-based (reactor-style) delivery");
+    println!("{} event(s) queued, none delivered yet", news_subject.pending());
+    while let Some(event) = news_subject.poll_for_event() {
+        println!("Draining queued event: {}", event.title);
+        news_subject.dispatch(&event);
+    }
+
+    println!("\n📝 Example 3: Detaching observer");
     news_subject.detach("Tech News Subscriber");
-    
+
     news_subject.publish_news(
         "Another Tech Update",
         "More technology news...",
         "Technology"
     );
-    
+    news_subject.dispatch_all();
+
     println!("\n💡 Interview Points:");
     println!("   • Weak references prevent memory leaks");
     println!("   • Interior mutability with RefCell");
     println!("   • Shared ownership with Rc");
     println!("   • Type safety with trait objects");
+    println!("   • Queued poll_for_event decouples publishing from delivery");
 }
@@ -1,4 +1,13 @@
 // Proxy Pattern Demo
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::task::{Context, Poll};
+use std::thread;
+
+use super::executor::block_on;
 
 pub trait Image {
     fn display(&self);
@@ -34,7 +43,7 @@ impl Image for RealImage {
 }
 
 pub struct ImageProxy {
-    real_image: Option<RealImage>,
+    real_image: RefCell<Option<RealImage>>,
     filename: String,
 }
 
@@ -42,7 +51,7 @@ impl ImageProxy {
     pub fn new(filename: &str) -> Self {
         println!("Creating image proxy: {}", filename);
         ImageProxy {
-            real_image: None,
+            real_image: RefCell::new(None),
             filename: filename.to_string(),
         }
     }
@@ -50,13 +59,149 @@ impl ImageProxy {
 
 impl Image for ImageProxy {
     fn display(&self) {
-        if self.real_image.is_none() {
+        let mut real_image = self.real_image.borrow_mut();
+        if real_image.is_none() {
             println!("Lazy loading initiated");
+            *real_image = Some(RealImage::new(&self.filename));
+        }
+        // The RealImage is built once and cached; `RealImage::display`
+        // itself still calls `load_from_disk` on every display.
+        real_image.as_ref().unwrap().display();
+    }
+}
+
+impl ImageProxy {
+    /// An async-flavored path to the same cached resource `display` uses,
+    /// mirroring the async payment submission path on the Strategy side of
+    /// this demo. Unlike `display`, the actual load happens on a spawned
+    /// thread via `AsyncLoad`, so this genuinely frees the caller's task to
+    /// make progress elsewhere while it waits - and it shares the proxy's
+    /// cache, so a prior `display()`/`load()` call still avoids a repeated
+    /// "load". The `RefCell` borrow is always dropped before the `.await`
+    /// point, since nothing else can run on this single-threaded demo while
+    /// a borrow from this call is alive.
+    pub async fn load(&self) -> RealImage {
+        if self.real_image.borrow().is_none() {
+            let filename = self.filename.clone();
+            let loaded = AsyncLoad::spawn(filename).await;
+            *self.real_image.borrow_mut() = Some(loaded);
+        }
+        // RealImage isn't Clone, so hand back a fresh handle onto the same
+        // file instead of trying to share the cached value by reference.
+        let real_image = self.real_image.borrow();
+        RealImage {
+            filename: real_image.as_ref().unwrap().filename.clone(),
+            loaded: true,
         }
-        
-        // Lazy loading happens here
-        let real_image = RealImage::new(&self.filename);
-        real_image.display();
+    }
+}
+
+/// Loads a [`RealImage`] on a spawned thread, polling a channel for the
+/// result rather than faking readiness on the calling thread - a genuine
+/// off-thread fetch instead of just an `.await`-shaped call site.
+struct AsyncLoad {
+    receiver: mpsc::Receiver<RealImage>,
+}
+
+impl AsyncLoad {
+    fn spawn(filename: String) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(RealImage::new(&filename));
+        });
+        AsyncLoad { receiver }
+    }
+}
+
+impl Future for AsyncLoad {
+    type Output = RealImage;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.receiver.try_recv() {
+            Ok(image) => Poll::Ready(image),
+            Err(_) => Poll::Pending,
+        }
+    }
+}
+
+/// An access-control error raised by [`ProtectedImageProxy`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessDenied {
+    pub domain: String,
+}
+
+impl std::fmt::Display for AccessDenied {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "domain '{}' is not allowed to access this image", self.domain)
+    }
+}
+
+/// A simple allow-list policy: a domain may access the protected resource
+/// only if it has been explicitly allowed, either directly or via a loaded
+/// policy file.
+pub struct AccessPolicy {
+    allowed_domains: HashSet<String>,
+}
+
+impl AccessPolicy {
+    pub fn new() -> Self {
+        AccessPolicy {
+            allowed_domains: HashSet::new(),
+        }
+    }
+
+    pub fn allow_domain(&mut self, domain: &str) {
+        self.allowed_domains.insert(domain.to_string());
+    }
+
+    /// Parses a newline-separated list of allowed domains, as if read from
+    /// a policy file on disk (one domain per line, blank lines ignored).
+    pub fn load_policy_file(&mut self, rules: &str) {
+        for line in rules.lines() {
+            let domain = line.trim();
+            if !domain.is_empty() {
+                self.allow_domain(domain);
+            }
+        }
+    }
+
+    pub fn is_allowed(&self, domain: &str) -> bool {
+        self.allowed_domains.contains(domain)
+    }
+}
+
+/// A protection proxy: unlike [`ImageProxy`], which only defers loading, this
+/// also enforces an [`AccessPolicy`] before delegating to the real image.
+pub struct ProtectedImageProxy {
+    real_image: RefCell<Option<RealImage>>,
+    filename: String,
+    policy: AccessPolicy,
+}
+
+impl ProtectedImageProxy {
+    pub fn new(filename: &str, policy: AccessPolicy) -> Self {
+        ProtectedImageProxy {
+            real_image: RefCell::new(None),
+            filename: filename.to_string(),
+            policy,
+        }
+    }
+
+    /// Displays the image on behalf of `caller_domain`, refusing access
+    /// when the policy does not permit it.
+    pub fn display(&self, caller_domain: &str) -> Result<(), AccessDenied> {
+        if !self.policy.is_allowed(caller_domain) {
+            return Err(AccessDenied {
+                domain: caller_domain.to_string(),
+            });
+        }
+
+        let mut real_image = self.real_image.borrow_mut();
+        if real_image.is_none() {
+            *real_image = Some(RealImage::new(&self.filename));
+        }
+        real_image.as_ref().unwrap().display();
+        Ok(())
     }
 }
 
@@ -66,20 +211,41 @@ pub fn demo_proxy() {
     println!("\nThis pattern provides a placeholder for another object.");
     println!("Rust Benefit: Lazy initialization and access control.");
     
-    println!("\n📝 Example 1: Lazy loading with proxy");
+    println!("\n📝 Example 1: Lazy loading with proxy (and caching)");
     println!("Note: Image is NOT loaded until display is called");
     let proxy = ImageProxy::new("huge_image.jpg");
-    
-    println!("\nNow accessing the image through proxy:");
+
+    println!("\nFirst access through proxy:");
     proxy.display();
-    
-    println!("\n📝 Example 2: Direct access (no proxy)");
+    println!("\nSecond access - cached RealImage reused, not rebuilt:");
+    proxy.display();
+
+    println!("\n📝 Example 2: Async access, still served from the shared cache");
+    let loaded = block_on(proxy.load());
+    loaded.display();
+
+    println!("\n📝 Example 3: Direct access (no proxy)");
     let direct_image = RealImage::new("direct.jpg");
     direct_image.display();
-    
+
+    println!("\n📝 Example 4: Protection proxy enforcing an access policy");
+    let mut policy = AccessPolicy::new();
+    policy.load_policy_file("intranet.corp\ntrusted-partner.com");
+    let protected = ProtectedImageProxy::new("confidential.jpg", policy);
+
+    match protected.display("intranet.corp") {
+        Ok(()) => println!("✅ Access granted"),
+        Err(e) => println!("❌ {e}"),
+    }
+    match protected.display("random-site.com") {
+        Ok(()) => println!("✅ Access granted"),
+        Err(e) => println!("❌ {e}"),
+    }
+
     println!("\n💡 Interview Points:");
     println!("   • Lazy initialization");
-    println!("   • Virtual proxy for expensive objects");
-    println!("   • Access control");
+    println!("   • Virtual proxy for expensive objects, cached after first load");
+    println!("   • Protection proxy enforces an access-control policy");
     println!("   • Reduce memory usage");
+    println!("   • Async load() shares the same cache as the sync display() path");
 }
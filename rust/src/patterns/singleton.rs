@@ -1,21 +1,21 @@
 // Singleton Pattern Demo
-use std::sync::{Mutex, Once, Arc};
 use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::SystemTime;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LogEntry {
     timestamp: SystemTime,
     level: LogLevel,
     message: String,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LogLevel {
+    Debug,
     Info,
     Warning,
     Error,
-    Debug,
 }
 
 impl std::fmt::Display for LogLevel {
@@ -29,73 +29,159 @@ impl std::fmt::Display for LogLevel {
     }
 }
 
+/// A pluggable output strategy for log entries (Strategy pattern), so the
+/// Logger doesn't hard-code `println!` as its only sink.
+pub trait LogSink {
+    fn write(&self, entry: &LogEntry);
+}
+
+pub struct StdoutSink;
+
+impl LogSink for StdoutSink {
+    fn write(&self, entry: &LogEntry) {
+        println!("[{}] {}", entry.level, entry.message);
+    }
+}
+
+/// An in-memory sink, useful for tests or for an app that wants to surface
+/// recent logs in a UI without re-reading stdout.
+pub struct BufferSink {
+    buffer: Mutex<Vec<String>>,
+}
+
+impl BufferSink {
+    pub fn new() -> Self {
+        BufferSink {
+            buffer: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn contents(&self) -> Vec<String> {
+        self.buffer.lock().unwrap().clone()
+    }
+}
+
+impl LogSink for BufferSink {
+    fn write(&self, entry: &LogEntry) {
+        self.buffer
+            .lock()
+            .unwrap()
+            .push(format!("[{}] {}", entry.level, entry.message));
+    }
+}
+
+/// Lets an `Arc<BufferSink>` (or any other `Arc`-wrapped sink) be handed to
+/// `Logger::add_sink` while the caller keeps its own handle to read the
+/// buffered contents back later.
+impl<T: LogSink + ?Sized> LogSink for Arc<T> {
+    fn write(&self, entry: &LogEntry) {
+        (**self).write(entry);
+    }
+}
+
 pub struct Logger {
     logs: VecDeque<LogEntry>,
     max_logs: usize,
+    sinks: Vec<Box<dyn LogSink + Send>>,
+    min_level: LogLevel,
 }
 
 impl Logger {
     pub fn get_instance() -> Arc<Mutex<Logger>> {
-        static INIT: Once = Once::new();
-        static mut INSTANCE: Option<Arc<Mutex<Logger>>> = None;
-        
-        unsafe {
-            INIT.call_once(|| {
-                INSTANCE = Some(Arc::new(Mutex::new(Logger {
+        static INSTANCE: OnceLock<Arc<Mutex<Logger>>> = OnceLock::new();
+        INSTANCE
+            .get_or_init(|| {
+                Arc::new(Mutex::new(Logger {
                     logs: VecDeque::new(),
                     max_logs: 1000,
-                })));
-            });
-            INSTANCE.as_ref().unwrap().clone()
-        }
+                    sinks: vec![Box::new(StdoutSink)],
+                    min_level: LogLevel::Debug,
+                }))
+            })
+            .clone()
+    }
+
+    pub fn add_sink(&mut self, sink: Box<dyn LogSink + Send>) {
+        self.sinks.push(sink);
+    }
+
+    pub fn set_min_level(&mut self, min_level: LogLevel) {
+        self.min_level = min_level;
     }
-    
+
     pub fn log(&mut self, level: LogLevel, message: &str) {
         let entry = LogEntry {
             timestamp: SystemTime::now(),
             level,
             message: message.to_string(),
         };
-        
+
+        if level >= self.min_level {
+            for sink in &self.sinks {
+                sink.write(&entry);
+            }
+        }
+
         self.logs.push_back(entry);
-        
         while self.logs.len() > self.max_logs {
             self.logs.pop_front();
         }
-        
-        println!("[{}] {}", level, message);
     }
-    
+
     pub fn info(&mut self, message: &str) {
         self.log(LogLevel::Info, message);
     }
-    
+
     pub fn warning(&mut self, message: &str) {
         self.log(LogLevel::Warning, message);
     }
-    
+
     pub fn error(&mut self, message: &str) {
         self.log(LogLevel::Error, message);
     }
-    
+
     pub fn debug(&mut self, message: &str) {
         self.log(LogLevel::Debug, message);
     }
-    
+
     pub fn get_recent_logs(&self, count: usize) -> Vec<&LogEntry> {
         self.logs.iter().rev().take(count).collect()
     }
+
+    /// Returns every stored entry at or above `min_level`, logged at or
+    /// after `since`.
+    pub fn query(&self, min_level: LogLevel, since: SystemTime) -> Vec<&LogEntry> {
+        self.logs
+            .iter()
+            .filter(|entry| entry.level >= min_level && entry.timestamp >= since)
+            .collect()
+    }
+
+    /// Formats a single entry using a small strftime-style pattern:
+    /// `%L` → level, `%M` → message, `%E` → seconds since the entry's
+    /// timestamp was recorded (a stand-in for a real strftime since this
+    /// crate has no date/time dependency).
+    pub fn render(&self, entry: &LogEntry, fmt: &str) -> String {
+        let elapsed = entry
+            .timestamp
+            .elapsed()
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        fmt.replace("%L", &entry.level.to_string())
+            .replace("%M", &entry.message)
+            .replace("%E", &elapsed.to_string())
+    }
 }
 
 pub fn demo_singleton() {
     println!("🔒 SINGLETON PATTERN DEMO");
     println!("{}", "=".repeat(60));
     println!("\nThis pattern ensures only one instance exists.");
-    println!("Rust Benefit: Arc<Mutex<T>> for thread safety.");
-    
+    println!("Rust Benefit: OnceLock<Arc<Mutex<T>>> for thread safety without unsafe.");
+
     println!("\n📝 Example 1: Thread-safe logging");
     let logger = Logger::get_instance();
-    
+
     {
         let mut logger = logger.lock().unwrap();
         logger.info("Application started");
@@ -103,20 +189,41 @@ pub fn demo_singleton() {
         logger.error("An error occurred");
         logger.debug("Debug information");
     }
-    
+
     println!("\n📝 Example 2: Getting singleton multiple times");
     let logger1 = Logger::get_instance();
     let logger2 = Logger::get_instance();
-    
-    // Both should be the same instance
+
     {
         logger1.lock().unwrap().info("Log from instance 1");
         logger2.lock().unwrap().info("Log from instance 2");
     }
-    
+
+    println!("\n📝 Example 3: Pluggable sinks and level filtering");
+    let since = SystemTime::now();
+    let buffer_sink = Arc::new(BufferSink::new());
+    {
+        let mut logger = logger.lock().unwrap();
+        logger.add_sink(Box::new(buffer_sink.clone()));
+        logger.set_min_level(LogLevel::Warning);
+        logger.debug("This debug message is filtered out of the sinks");
+        logger.error("This error message still reaches every sink");
+    }
+    println!("Captured by BufferSink: {:?}", buffer_sink.contents());
+
+    println!("\n📝 Example 4: Querying and rendering logs");
+    {
+        let logger = logger.lock().unwrap();
+        let warnings_and_up = logger.query(LogLevel::Warning, since);
+        println!("Found {} entr(y/ies) at Warning or above since Example 3 began:", warnings_and_up.len());
+        for entry in &warnings_and_up {
+            println!("   {}", logger.render(entry, "[%L] %M (%Es ago)"));
+        }
+    }
+
     println!("\n💡 Interview Points:");
+    println!("   • OnceLock replaces static mut + unsafe for the singleton's storage");
+    println!("   • LogSink (Strategy) makes output pluggable: stdout, buffer, etc.");
+    println!("   • Level filtering and time-based querying beyond get_recent_logs");
     println!("   • Thread safety with Arc<Mutex<T>>");
-    println!("   • Static initialization with Once");
-    println!("   • No null pointer dereferences");
-    println!("   • Compile-time guarantees");
 }
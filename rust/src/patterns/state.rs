@@ -1,74 +1,224 @@
 // State Pattern Demo
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::rc::Rc;
 
-pub enum State {
-    Idle,
-    Active,
-    Processing,
+/// A transition was rejected: either no transition is declared for the
+/// current `(state, event)` pair, or a guard returned `false`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransitionError {
+    NoMatchingTransition,
+    GuardRejected,
 }
 
-pub struct Context {
-    state: State,
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitionError::NoMatchingTransition => write!(f, "no transition declared for this event"),
+            TransitionError::GuardRejected => write!(f, "transition guard rejected this event"),
+        }
+    }
+}
+
+type Guard<S, E> = Box<dyn Fn(&S, &E) -> bool>;
+type Action<S> = Box<dyn Fn(&S)>;
+
+struct Transition<S, E> {
+    to: S,
+    guard: Option<Guard<S, E>>,
 }
 
-impl Context {
-    pub fn new() -> Self {
-        Context {
-            state: State::Idle,
+/// A generic, data-driven state machine: states and events are supplied by
+/// the caller, transitions are declared rather than hard-coded into an
+/// `if`/`match` chain, and entry/exit actions run automatically on change.
+pub struct StateMachine<S, E> {
+    current: S,
+    transitions: HashMap<(S, E), Transition<S, E>>,
+    on_enter: HashMap<S, Action<S>>,
+    on_exit: HashMap<S, Action<S>>,
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: Eq + Hash + Clone,
+    E: Eq + Hash + Clone,
+{
+    pub fn new(initial: S) -> Self {
+        StateMachine {
+            current: initial,
+            transitions: HashMap::new(),
+            on_enter: HashMap::new(),
+            on_exit: HashMap::new(),
         }
     }
-  
-    pub fn set_state(&mut self, state: State) {
-        self.state = state;
+
+    /// Declares an unguarded transition `(from, event) -> to`; use
+    /// `add_guarded_transition` for a guarded one.
+    pub fn add_transition(&mut self, from: S, event: E, to: S) -> &mut Self {
+        self.transitions.insert((from, event), Transition { to, guard: None });
+        self
     }
-  
-    pub fn request(&mut self) -> String {
-        match self.state {
-            State::Idle => {
-                println!("Current state: Idle");
-                println!("Transitioning to Active...");
-                self.set_state(State::Active);
-                "Idle → Active".to_string()
-            }
-            State::Active => {
-                println!("Current state: Active");
-                println!("Transitioning to Processing...");
-                self.set_state(State::Processing);
-                "Active → Processing".to_string()
-            }
-            State::Processing => {
-                println!("Current state: Processing");
-                println!("Transitioning back to Idle...");
-                self.set_state(State::Idle);
-                "Processing → Idle".to_string()
+
+    /// Declares a guarded transition: it only fires when `guard` returns
+    /// `true` for the current state and incoming event.
+    pub fn add_guarded_transition(
+        &mut self,
+        from: S,
+        event: E,
+        to: S,
+        guard: impl Fn(&S, &E) -> bool + 'static,
+    ) -> &mut Self {
+        self.transitions.insert(
+            (from, event),
+            Transition {
+                to,
+                guard: Some(Box::new(guard)),
+            },
+        );
+        self
+    }
+
+    /// Registers an action run whenever the machine enters `state`.
+    pub fn on_enter(&mut self, state: S, action: impl Fn(&S) + 'static) -> &mut Self {
+        self.on_enter.insert(state, Box::new(action));
+        self
+    }
+
+    /// Registers an action run whenever the machine leaves `state`.
+    pub fn on_exit(&mut self, state: S, action: impl Fn(&S) + 'static) -> &mut Self {
+        self.on_exit.insert(state, Box::new(action));
+        self
+    }
+
+    pub fn current_state(&self) -> &S {
+        &self.current
+    }
+
+    /// Looks up the declared transition for `(current_state, event)`, checks
+    /// its guard, and if it fires, runs exit-then-entry actions and updates
+    /// the current state.
+    pub fn dispatch(&mut self, event: E) -> Result<&S, TransitionError> {
+        let key = (self.current.clone(), event.clone());
+        let transition = self
+            .transitions
+            .get(&key)
+            .ok_or(TransitionError::NoMatchingTransition)?;
+
+        if let Some(guard) = &transition.guard {
+            if !guard(&self.current, &event) {
+                return Err(TransitionError::GuardRejected);
             }
         }
+
+        let to = transition.to.clone();
+
+        if let Some(exit_action) = self.on_exit.get(&self.current) {
+            exit_action(&self.current);
+        }
+        self.current = to;
+        if let Some(enter_action) = self.on_enter.get(&self.current) {
+            enter_action(&self.current);
+        }
+
+        Ok(&self.current)
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderState {
+    Placed,
+    Paid,
+    Shipped,
+    Delivered,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderEvent {
+    ReceivePayment,
+    Ship,
+    Deliver,
+    Cancel,
+}
+
+/// Builds a small order-workflow engine on top of [`StateMachine`],
+/// replacing the old hard-coded Idle → Active → Processing cycle.
+///
+/// `cancellation_window` is shared with the caller so it can flip closed
+/// (e.g. once the warehouse starts packing) to demonstrate the `(Paid,
+/// Cancel)` guard genuinely rejecting a transition - unlike a guard keyed on
+/// `current != Shipped`, which can never see anything but `Paid` and so can
+/// never evaluate false.
+pub fn build_order_workflow(cancellation_window: Rc<Cell<bool>>) -> StateMachine<OrderState, OrderEvent> {
+    let mut machine = StateMachine::new(OrderState::Placed);
+
+    machine
+        .add_transition(OrderState::Placed, OrderEvent::ReceivePayment, OrderState::Paid)
+        .add_transition(OrderState::Placed, OrderEvent::Cancel, OrderState::Cancelled)
+        .add_transition(OrderState::Paid, OrderEvent::Ship, OrderState::Shipped)
+        .add_guarded_transition(
+            OrderState::Paid,
+            OrderEvent::Cancel,
+            OrderState::Cancelled,
+            move |_state, _event| cancellation_window.get(),
+        )
+        .add_transition(OrderState::Shipped, OrderEvent::Deliver, OrderState::Delivered)
+        .on_enter(OrderState::Shipped, |_| println!("📦 Order has shipped"))
+        .on_enter(OrderState::Delivered, |_| println!("🏠 Order delivered"))
+        .on_exit(OrderState::Placed, |_| println!("Leaving Placed state"));
+
+    machine
+}
+
 pub fn demo_state() {
     println!("🔄 STATE PATTERN DEMO");
     println!("{}", "=".repeat(60));
     println!("\nThis pattern allows object behavior to change with state.");
-    println!("Rust Benefit: State machine with type safety.");
-    
-    println!("\n📝 Example 1: Simple state machine");
-    let mut context = Context::new();
-    
-    println!("\nRequest 1:");
-    println!("{}", context.request());
-    
-    println!("\nRequest 2:");
-    println!("{}", context.request());
-    
-    println!("\nRequest 3:");
-    println!("{}", context.request());
-    
-    println!("\nRequest 4:");
-    println!("{}", context.request());
-    
+    println!("Rust Benefit: A generic, guarded, data-driven state machine.");
+
+    println!("\n📝 Example 1: Order workflow engine");
+    let mut workflow = build_order_workflow(Rc::new(Cell::new(true)));
+    println!("Current state: {:?}", workflow.current_state());
+
+    for event in [
+        OrderEvent::ReceivePayment,
+        OrderEvent::Ship,
+        OrderEvent::Deliver,
+    ] {
+        match workflow.dispatch(event) {
+            Ok(state) => println!("{:?} → now in {:?}", event, state),
+            Err(e) => println!("{:?} rejected: {e}", event),
+        }
+    }
+
+    println!("\n📝 Example 2: Rejected transitions");
+    let cancellation_window = Rc::new(Cell::new(true));
+    let mut fresh_order = build_order_workflow(cancellation_window.clone());
+    fresh_order.dispatch(OrderEvent::ReceivePayment).unwrap();
+
+    println!("Closing the cancellation window (e.g. the warehouse started packing):");
+    cancellation_window.set(false);
+    match fresh_order.dispatch(OrderEvent::Cancel) {
+        Ok(state) => println!("Cancel → {:?}", state),
+        Err(e) => println!("Cancel rejected (cancellation window closed): {e}"),
+    }
+
+    fresh_order.dispatch(OrderEvent::Ship).unwrap();
+    match fresh_order.dispatch(OrderEvent::Cancel) {
+        Ok(state) => println!("Cancel → {:?}", state),
+        Err(e) => println!("Cancel rejected (order already shipped): {e}"),
+    }
+    match fresh_order.dispatch(OrderEvent::ReceivePayment) {
+        Ok(state) => println!("ReceivePayment → {:?}", state),
+        Err(e) => println!("ReceivePayment rejected (no such transition): {e}"),
+    }
+
     println!("\n💡 Interview Points:");
     println!("   • Behavior depends on internal state");
-    println!("   • Encapsulate state transitions");
-    println!("   • Avoid large if/else chains");
+    println!("   • Declare transitions as data instead of if/else chains");
+    println!("   • Guards make some transitions conditional on extra logic");
+    println!("   • Entry/exit actions run automatically on every state change");
     println!("   • Use case: game entities, workflow engines");
 }
@@ -1,4 +1,12 @@
 // Strategy Pattern Demo
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::executor::block_on;
 
 pub trait PaymentStrategy {
     fn pay(&self, amount: f64) -> String;
@@ -34,12 +42,152 @@ impl PaymentProcessor {
     pub fn new(strategy: Box<dyn PaymentStrategy>) -> Self {
         PaymentProcessor { strategy }
     }
-  
+
     pub fn process_payment(&self, amount: f64) -> String {
         self.strategy.pay(amount)
     }
 }
 
+/// Errors returned by the async payment path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentError {
+    /// A transient failure that is worth retrying (e.g. a timed-out gateway).
+    Transient(String),
+    /// A terminal failure that retrying will not fix (e.g. card declined).
+    Declined(String),
+}
+
+/// A non-blocking counterpart of [`PaymentStrategy`]. Unlike the sync trait,
+/// which returns a plain `String`, this models a gateway that can fail
+/// transiently and needs retry/backoff handling by the caller.
+pub trait AsyncPaymentStrategy {
+    fn pay<'a>(
+        &'a self,
+        amount: f64,
+    ) -> Pin<Box<dyn Future<Output = Result<String, PaymentError>> + 'a>>;
+}
+
+pub struct AsyncCreditCardPayment {
+    /// Counts down on each call; simulates a gateway that times out on its
+    /// first few attempts before succeeding.
+    remaining_timeouts: Cell<u32>,
+    /// Simulates a card the issuer refuses outright - a terminal failure no
+    /// amount of retrying will fix.
+    declined: bool,
+}
+
+impl AsyncCreditCardPayment {
+    pub fn new() -> Self {
+        AsyncCreditCardPayment {
+            remaining_timeouts: Cell::new(0),
+            declined: false,
+        }
+    }
+
+    pub fn flaky(flaky_attempts: u32) -> Self {
+        AsyncCreditCardPayment {
+            remaining_timeouts: Cell::new(flaky_attempts),
+            declined: false,
+        }
+    }
+
+    /// A card that the issuer declines every time - always `Declined`, never
+    /// `Transient`, so the caller's retry loop shouldn't touch it.
+    pub fn declined() -> Self {
+        AsyncCreditCardPayment {
+            remaining_timeouts: Cell::new(0),
+            declined: true,
+        }
+    }
+}
+
+impl AsyncPaymentStrategy for AsyncCreditCardPayment {
+    fn pay<'a>(
+        &'a self,
+        amount: f64,
+    ) -> Pin<Box<dyn Future<Output = Result<String, PaymentError>> + 'a>> {
+        Box::pin(async move {
+            if self.declined {
+                return Err(PaymentError::Declined(
+                    "card declined by issuer".to_string(),
+                ));
+            }
+            let remaining = self.remaining_timeouts.get();
+            if remaining > 0 {
+                self.remaining_timeouts.set(remaining - 1);
+                return Err(PaymentError::Transient(
+                    "gateway timeout, try again".to_string(),
+                ));
+            }
+            Ok(format!("Paid ${:.2} using Credit Card (async)", amount))
+        })
+    }
+}
+
+/// A handle to a payment submitted without waiting for confirmation,
+/// mirroring a fire-and-forget async client.
+pub struct PaymentHandle {
+    future: Pin<Box<dyn Future<Output = Result<String, PaymentError>>>>,
+}
+
+impl PaymentHandle {
+    /// Blocks on the handle to retrieve the eventual confirmation. Separated
+    /// from `submit` so callers can do other work before collecting it.
+    pub fn confirm(self) -> Result<String, PaymentError> {
+        block_on(self.future)
+    }
+}
+
+pub struct AsyncPaymentProcessor {
+    /// Shared via `Arc` rather than owned via `Box` so `submit` can clone a
+    /// handle into a `'static` future instead of faking the outcome.
+    strategy: Arc<dyn AsyncPaymentStrategy>,
+    max_retries: u32,
+}
+
+impl AsyncPaymentProcessor {
+    pub fn new(strategy: Box<dyn AsyncPaymentStrategy>, max_retries: u32) -> Self {
+        AsyncPaymentProcessor {
+            strategy: Arc::from(strategy),
+            max_retries,
+        }
+    }
+
+    /// Send-and-confirm: await the payment inline, retrying transient
+    /// failures up to `max_retries` times with a short backoff between
+    /// attempts before surfacing the final `Result`.
+    pub fn process_payment_confirmed(&self, amount: f64) -> Result<String, PaymentError> {
+        block_on(async {
+            let mut attempt = 0;
+            loop {
+                match self.strategy.pay(amount).await {
+                    Ok(receipt) => return Ok(receipt),
+                    Err(PaymentError::Transient(reason)) if attempt < self.max_retries => {
+                        attempt += 1;
+                        println!(
+                            "   ⏳ Transient error ({reason}), retrying attempt {attempt}/{}",
+                            self.max_retries
+                        );
+                        thread::sleep(Duration::from_millis(10 * attempt as u64));
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        })
+    }
+
+    /// Submit without awaiting confirmation; the caller decides when (or
+    /// whether) to collect the result via the returned handle. Clones the
+    /// `Arc<dyn AsyncPaymentStrategy>` into the handle's future so it
+    /// actually drives `strategy.pay` instead of fabricating a receipt.
+    pub fn submit(&self, amount: f64) -> PaymentHandle {
+        let strategy = Arc::clone(&self.strategy);
+        PaymentHandle {
+            future: Box::pin(async move { strategy.pay(amount).await }),
+        }
+    }
+}
+
 pub fn demo_strategy() {
     println!("🎯 STRATEGY PATTERN DEMO");
     println!("{}", "=".repeat(60));
@@ -56,10 +204,45 @@ pub fn demo_strategy() {
     
     let bitcoin_processor = PaymentProcessor::new(Box::new(BitcoinPayment));
     println!("{}", bitcoin_processor.process_payment(75.5));
-    
+
+    println!("\n📝 Example 2: Async payment with retry/confirm semantics");
+    let flaky_processor =
+        AsyncPaymentProcessor::new(Box::new(AsyncCreditCardPayment::flaky(2)), 3);
+    match flaky_processor.process_payment_confirmed(42.0) {
+        Ok(receipt) => println!("✅ {}", receipt),
+        Err(e) => println!("❌ Payment failed: {:?}", e),
+    }
+
+    println!("\n📝 Example 3: Fire-and-forget async submission");
+    let async_processor = AsyncPaymentProcessor::new(Box::new(AsyncCreditCardPayment::new()), 3);
+    let handle = async_processor.submit(15.0);
+    println!("Submitted, continuing with other work...");
+    match handle.confirm() {
+        Ok(receipt) => println!("✅ {}", receipt),
+        Err(e) => println!("❌ Submission failed: {:?}", e),
+    }
+
+    println!("\n📝 Example 4: submit() reflects the strategy's real outcome");
+    let doomed_processor =
+        AsyncPaymentProcessor::new(Box::new(AsyncCreditCardPayment::flaky(99)), 0);
+    let doomed_handle = doomed_processor.submit(30.0);
+    match doomed_handle.confirm() {
+        Ok(receipt) => println!("✅ {}", receipt),
+        Err(e) => println!("❌ Submission failed as expected: {:?}", e),
+    }
+
+    println!("\n📝 Example 5: Declined card is terminal, not retried");
+    let declined_processor = AsyncPaymentProcessor::new(Box::new(AsyncCreditCardPayment::declined()), 3);
+    match declined_processor.process_payment_confirmed(20.0) {
+        Ok(receipt) => println!("✅ {}", receipt),
+        Err(e) => println!("❌ Declined immediately, no retry attempted: {:?}", e),
+    }
+
     println!("\n💡 Interview Points:");
     println!("   • Encapsulate algorithms in separate types");
     println!("   • Runtime selection using trait objects");
     println!("   • Easy to add new strategies");
     println!("   • Open/Closed Principle compliance");
+    println!("   • Async strategies: retry/confirm vs fire-and-forget submission");
+    println!("   • PaymentError distinguishes retryable Transient failures from terminal Declined ones");
 }
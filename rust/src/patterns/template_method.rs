@@ -1,4 +1,57 @@
 // Template Method Pattern Demo
+use std::collections::HashMap;
+
+use super::conversion::{Conversion, TypedValue};
+
+/// Errors raised while applying a column's declared [`Conversion`]. The
+/// coercion itself is shared with the Builder demo (see
+/// [`super::conversion`]); this error type stays local since its `target`
+/// label is specific to how this demo reports failures.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    UnknownConversion(String),
+    ParseFailed { raw: String, target: String },
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::UnknownConversion(raw) => write!(f, "unknown conversion '{raw}'"),
+            ConversionError::ParseFailed { raw, target } => {
+                write!(f, "could not parse '{raw}' as {target}")
+            }
+        }
+    }
+}
+
+/// Describes `conversion` for use in a [`ConversionError::ParseFailed`]
+/// message, e.g. `"integer"` or `"timestamp (%Y-%m-%d)"`.
+fn target_label(conversion: &Conversion) -> String {
+    match conversion {
+        Conversion::Bytes => "bytes".to_string(),
+        Conversion::Integer => "integer".to_string(),
+        Conversion::Float => "float".to_string(),
+        Conversion::Boolean => "boolean".to_string(),
+        Conversion::Timestamp => "timestamp".to_string(),
+        Conversion::TimestampFmt(fmt) => format!("timestamp ({fmt})"),
+    }
+}
+
+/// Applies `conversion` to `raw`, wrapping the shared
+/// [`Conversion::parse`]'s `Option` in this demo's own [`ConversionError`].
+fn apply(conversion: &Conversion, raw: &str) -> Result<TypedValue, ConversionError> {
+    conversion.parse(raw).ok_or_else(|| ConversionError::ParseFailed {
+        raw: raw.to_string(),
+        target: target_label(conversion),
+    })
+}
+
+/// Parses a conversion spec like `"int"` or `"timestamp|%Y-%m-%d"` into a
+/// [`Conversion`], wrapping the shared `FromStr`'s `Err` in this demo's own
+/// [`ConversionError`].
+fn parse_conversion_spec(spec: &str) -> Result<Conversion, ConversionError> {
+    spec.parse().map_err(ConversionError::UnknownConversion)
+}
 
 pub trait DataProcessor {
     fn process(&self) {
@@ -11,11 +64,38 @@ pub trait DataProcessor {
         println!("{}", "=".repeat(40));
         println!("✅ Processing complete!");
     }
-  
+
     fn load_data(&self);
     fn validate_data(&self);
-    fn transform_data(&self);
+
+    /// Default transform step: looks up each declared column's conversion
+    /// and applies it, reporting any parse failures instead of ignoring
+    /// them silently.
+    fn transform_data(&self) {
+        println!("🔄 Transforming data using declared column conversions...");
+        for (column, conversion) in self.conversions() {
+            for raw in self.sample_row(&column) {
+                match apply(&conversion, raw) {
+                    Ok(value) => println!("   {column}: '{raw}' → {value:?}"),
+                    Err(e) => println!("   {column}: ❌ {e}"),
+                }
+            }
+        }
+    }
+
     fn save_data(&self);
+
+    /// Declares which [`Conversion`] applies to each column name. The
+    /// default is empty so existing processors keep compiling unchanged.
+    fn conversions(&self) -> HashMap<String, Conversion> {
+        HashMap::new()
+    }
+
+    /// Raw sample values for `column`, used by the default `transform_data`
+    /// to demonstrate the conversion. Empty by default.
+    fn sample_row(&self, _column: &str) -> Vec<&str> {
+        Vec::new()
+    }
 }
 
 pub struct CSVProcessor;
@@ -24,18 +104,31 @@ impl DataProcessor for CSVProcessor {
     fn load_data(&self) {
         println!("📁 Loading CSV data...");
     }
-  
+
     fn validate_data(&self) {
         println!("✓ Validating CSV format...");
     }
-  
-    fn transform_data(&self) {
-        println!("🔄 Transforming CSV data...");
-    }
-  
+
     fn save_data(&self) {
         println!("💾 Saving processed CSV data...");
     }
+
+    fn conversions(&self) -> HashMap<String, Conversion> {
+        HashMap::from([
+            ("user_id".to_string(), Conversion::Integer),
+            ("signup_date".to_string(), Conversion::TimestampFmt("%Y-%m-%d".to_string())),
+            ("is_active".to_string(), Conversion::Boolean),
+        ])
+    }
+
+    fn sample_row(&self, column: &str) -> Vec<&str> {
+        match column {
+            "user_id" => vec!["1042"],
+            "signup_date" => vec!["2024-03-15"],
+            "is_active" => vec!["true"],
+            _ => Vec::new(),
+        }
+    }
 }
 
 pub struct JSONProcessor;
@@ -44,18 +137,29 @@ impl DataProcessor for JSONProcessor {
     fn load_data(&self) {
         println!("📁 Loading JSON data...");
     }
-  
+
     fn validate_data(&self) {
         println!("✓ Validating JSON format...");
     }
-  
-    fn transform_data(&self) {
-        println!("🔄 Transforming JSON data...");
-    }
-  
+
     fn save_data(&self) {
         println!("💾 Saving processed JSON data...");
     }
+
+    fn conversions(&self) -> HashMap<String, Conversion> {
+        HashMap::from([
+            ("latency_ms".to_string(), Conversion::Float),
+            ("request_id".to_string(), Conversion::Bytes),
+        ])
+    }
+
+    fn sample_row(&self, column: &str) -> Vec<&str> {
+        match column {
+            "latency_ms" => vec!["12.5", "not-a-number"],
+            "request_id" => vec!["req-abc123"],
+            _ => Vec::new(),
+        }
+    }
 }
 
 pub fn demo_template_method() {
@@ -63,18 +167,27 @@ pub fn demo_template_method() {
     println!("{}", "=".repeat(60));
     println!("\nThis pattern defines algorithm skeleton with customizable steps.");
     println!("Rust Benefit: Trait default implementations.");
-    
-    println!("\n📝 Example 1: CSV processing");
+
+    println!("\n📝 Example 1: CSV processing with typed conversions");
     let csv_processor = CSVProcessor;
     csv_processor.process();
-    
-    println!("\n📝 Example 2: JSON processing");
+
+    println!("\n📝 Example 2: JSON processing with typed conversions");
     let json_processor = JSONProcessor;
     json_processor.process();
-    
+
+    println!("\n📝 Example 3: Parsing conversions from config strings");
+    for spec in ["int", "timestamp|%Y-%m-%d", "bool", "nope"] {
+        match parse_conversion_spec(spec) {
+            Ok(conversion) => println!("   '{spec}' → {conversion:?}"),
+            Err(e) => println!("   '{spec}' → ❌ {e}"),
+        }
+    }
+
     println!("\n💡 Interview Points:");
     println!("   • Define algorithm structure in base trait");
     println!("   • Subclasses provide specific implementations");
     println!("   • Reduce code duplication");
+    println!("   • Schema-driven conversions keep the pipeline declarative");
     println!("   • Hollywood Principle (Don't call us, we'll call you)");
 }
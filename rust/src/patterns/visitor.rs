@@ -1,13 +1,13 @@
 // Visitor Pattern Demo
 
 pub trait Element {
-    fn accept(&self, visitor: &dyn Visitor);
+    fn accept(&self, visitor: &mut dyn Visitor);
     fn get_name(&self) -> &str;
 }
 
 pub trait Visitor {
-    fn visit_element_a(&self, element: &ElementA);
-    fn visit_element_b(&self, element: &ElementB);
+    fn visit_element_a(&mut self, element: &ElementA);
+    fn visit_element_b(&mut self, element: &ElementB);
 }
 
 pub struct ElementA {
@@ -21,10 +21,10 @@ impl ElementA {
 }
 
 impl Element for ElementA {
-    fn accept(&self, visitor: &dyn Visitor) {
+    fn accept(&self, visitor: &mut dyn Visitor) {
         visitor.visit_element_a(self);
     }
-    
+
     fn get_name(&self) -> &str {
         "ElementA"
     }
@@ -43,10 +43,10 @@ impl ElementB {
 }
 
 impl Element for ElementB {
-    fn accept(&self, visitor: &dyn Visitor) {
+    fn accept(&self, visitor: &mut dyn Visitor) {
         visitor.visit_element_b(self);
     }
-    
+
     fn get_name(&self) -> &str {
         "ElementB"
     }
@@ -55,11 +55,11 @@ impl Element for ElementB {
 pub struct ConcreteVisitor;
 
 impl Visitor for ConcreteVisitor {
-    fn visit_element_a(&self, element: &ElementA) {
+    fn visit_element_a(&mut self, element: &ElementA) {
         println!("Visiting ElementA with value: {}", element.value);
     }
-  
-    fn visit_element_b(&self, element: &ElementB) {
+
+    fn visit_element_b(&mut self, element: &ElementB) {
         println!("Visiting ElementB with value: {}", element.value);
     }
 }
@@ -72,19 +72,104 @@ impl CountVisitor {
     pub fn new() -> Self {
         CountVisitor { count: 0 }
     }
-    
+
     pub fn get_count(&self) -> usize {
         self.count
     }
 }
 
 impl Visitor for CountVisitor {
-    fn visit_element_a(&self, _element: &ElementA) {
-        println!("Counting ElementA...");
+    fn visit_element_a(&mut self, _element: &ElementA) {
+        self.count += 1;
+        println!("Counting ElementA... (total so far: {})", self.count);
     }
-  
-    fn visit_element_b(&self, _element: &ElementB) {
-        println!("Counting ElementB...");
+
+    fn visit_element_b(&mut self, _element: &ElementB) {
+        self.count += 1;
+        println!("Counting ElementB... (total so far: {})", self.count);
+    }
+}
+
+/// How serious a [`Diagnostic`] is, mirroring the severity levels a
+/// compiler or linter would report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A single finding emitted while walking an AST-like element tree.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub element_name: String,
+    pub message: String,
+}
+
+/// Walks a slice of `Element`s and collects structured findings, the way a
+/// linter's AST visitor would flag issues instead of just printing them.
+pub struct DiagnosticVisitor {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl DiagnosticVisitor {
+    pub fn new() -> Self {
+        DiagnosticVisitor {
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Visits every element in `elements`, accumulating diagnostics.
+    pub fn run(&mut self, elements: &[Box<dyn Element>]) {
+        for element in elements {
+            element.accept(self);
+        }
+    }
+
+    /// Consumes the visitor, returning everything it collected.
+    pub fn finish(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+}
+
+impl Visitor for DiagnosticVisitor {
+    fn visit_element_a(&mut self, element: &ElementA) {
+        if element.value < 0 {
+            self.diagnostics.push(Diagnostic {
+                severity: Severity::Error,
+                element_name: "ElementA".to_string(),
+                message: format!("negative value {} is not allowed", element.value),
+            });
+        } else if element.value > 1000 {
+            self.diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                element_name: "ElementA".to_string(),
+                message: format!("value {} looks suspiciously large", element.value),
+            });
+        } else {
+            self.diagnostics.push(Diagnostic {
+                severity: Severity::Info,
+                element_name: "ElementA".to_string(),
+                message: format!("value {} is within range", element.value),
+            });
+        }
+    }
+
+    fn visit_element_b(&mut self, element: &ElementB) {
+        if element.value.is_empty() {
+            self.diagnostics.push(Diagnostic {
+                severity: Severity::Error,
+                element_name: "ElementB".to_string(),
+                message: "empty string value".to_string(),
+            });
+        } else {
+            self.diagnostics.push(Diagnostic {
+                severity: Severity::Info,
+                element_name: "ElementB".to_string(),
+                message: format!("string value '{}' looks fine", element.value),
+            });
+        }
     }
 }
 
@@ -93,22 +178,45 @@ pub fn demo_visitor() {
     println!("{}", "=".repeat(60));
     println!("\nThis pattern defines operations on object structures.");
     println!("Rust Benefit: Separate algorithms from object structure.");
-    
+
     println!("\n📝 Example 1: Concrete visitor");
-    let visitor = ConcreteVisitor;
+    let mut visitor = ConcreteVisitor;
     let elements: Vec<Box<dyn Element>> = vec![
         Box::new(ElementA::new(42)),
         Box::new(ElementB::new("Hello")),
         Box::new(ElementA::new(100)),
     ];
-    
+
+    for element in &elements {
+        element.accept(&mut visitor);
+    }
+
+    println!("\n📝 Example 2: Counting visited nodes");
+    let mut counter = CountVisitor::new();
     for element in &elements {
-        element.accept(&visitor);
+        element.accept(&mut counter);
     }
-    
+    println!("Total elements visited: {}", counter.get_count());
+
+    println!("\n📝 Example 3: Diagnostic visitor (linter-style findings)");
+    let lint_elements: Vec<Box<dyn Element>> = vec![
+        Box::new(ElementA::new(-5)),
+        Box::new(ElementB::new("")),
+        Box::new(ElementA::new(5000)),
+        Box::new(ElementB::new("valid")),
+    ];
+    let mut diagnostic_visitor = DiagnosticVisitor::new();
+    diagnostic_visitor.run(&lint_elements);
+    for diagnostic in diagnostic_visitor.finish() {
+        println!(
+            "   [{:?}] {}: {}",
+            diagnostic.severity, diagnostic.element_name, diagnostic.message
+        );
+    }
+
     println!("\n💡 Interview Points:");
     println!("   • Add new operations without modifying elements");
     println!("   • Separate algorithms from object structures");
     println!("   • Use case: compiler AST visitors, type checkers");
-    println!("   • Double dispatch simulation");
+    println!("   • &mut self visitors let passes accumulate state (counts, diagnostics)");
 }